@@ -0,0 +1,44 @@
+//!Compares const naive scans against runtime search on 64KB buffer.
+//!
+//!Run with `cargo bench --bench search --features memchr` to see `memchr` acceleration,
+//!or without the feature to see `core` fallback.
+use str_buf::StrBuf;
+
+use std::time::{Duration, Instant};
+
+type BigStr = StrBuf<67_000>;
+
+const ITERATIONS: u32 = 1_000;
+
+//`std::hint::black_box` requires 1.66, newer than MSRV
+fn black_box<T>(value: T) -> T {
+    unsafe {
+        let result = core::ptr::read_volatile(&value);
+        core::mem::forget(value);
+        result
+    }
+}
+
+fn measure<F: FnMut() -> Option<usize>>(name: &str, mut cb: F) {
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        black_box(cb());
+        total += start.elapsed();
+    }
+    println!("{:<24} {:>12?}/iter", name, total / ITERATIONS);
+}
+
+fn main() {
+    let mut text = BigStr::new();
+    text.extend_with('a', 64 * 1024 - 16);
+    text.push_str("needle:");
+    let text = black_box(&text);
+
+    measure("find_byte (const)", || text.find_byte(b':'));
+    measure("memchr", || text.memchr(b':'));
+    measure("rfind_byte (const)", || text.rfind_byte(b'b'));
+    measure("memrchr", || text.memrchr(b'b'));
+    measure("const_find (const)", || text.const_find("needle"));
+    measure("memmem", || text.memmem("needle"));
+}
@@ -0,0 +1,65 @@
+use core::{cmp, hash};
+
+use crate::StrBuf;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+///Wrapper over `StrBuf`, which compares, orders and hashes content ignoring ASCII case.
+///
+///Suitable as key for maps and sorted tables, e.g. of HTTP header names.
+pub struct CaseInsensitive<const N: usize>(pub StrBuf<N>);
+
+impl<const N: usize> CaseInsensitive<N> {
+    #[inline(always)]
+    ///Access underlying string.
+    pub const fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[inline(always)]
+    ///Returns underlying buffer.
+    pub const fn into_inner(self) -> StrBuf<N> {
+        self.0
+    }
+}
+
+impl<const N: usize> From<StrBuf<N>> for CaseInsensitive<N> {
+    #[inline(always)]
+    fn from(buf: StrBuf<N>) -> Self {
+        Self(buf)
+    }
+}
+
+impl<const N: usize> PartialEq for CaseInsensitive<N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl<const N: usize> Eq for CaseInsensitive<N> {}
+
+impl<const N: usize> cmp::Ord for CaseInsensitive<N> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let left = self.as_str().bytes().map(|byte| byte.to_ascii_lowercase());
+        let right = other.as_str().bytes().map(|byte| byte.to_ascii_lowercase());
+        left.cmp(right)
+    }
+}
+
+impl<const N: usize> PartialOrd for CaseInsensitive<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> hash::Hash for CaseInsensitive<N> {
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        for byte in self.as_str().bytes() {
+            hasher.write_u8(byte.to_ascii_lowercase());
+        }
+        //Same terminator as `str` uses to keep prefixes distinct
+        hasher.write_u8(0xff);
+    }
+}
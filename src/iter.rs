@@ -0,0 +1,119 @@
+use core::iter::FusedIterator;
+
+use crate::StrBuf;
+
+#[derive(Clone)]
+///Owning iterator over characters of `StrBuf`, created by `IntoIterator::into_iter`.
+pub struct IntoChars<const N: usize> {
+    buf: StrBuf<N>,
+    front: usize,
+    back: usize,
+}
+
+impl<const N: usize> IntoChars<N> {
+    #[inline(always)]
+    pub(crate) const fn new(buf: StrBuf<N>) -> Self {
+        Self {
+            front: 0,
+            back: buf.len(),
+            buf,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns not yet iterated part of the string.
+    pub fn as_str(&self) -> &str {
+        //Cursors are always on char boundaries
+        unsafe {
+            self.buf.as_str().get_unchecked(self.front..self.back)
+        }
+    }
+}
+
+impl<const N: usize> Iterator for IntoChars<N> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.as_str().chars().next()?;
+        self.front += ch.len_utf8();
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for IntoChars<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ch = self.as_str().chars().next_back()?;
+        self.back -= ch.len_utf8();
+        Some(ch)
+    }
+}
+
+impl<const N: usize> FusedIterator for IntoChars<N> {}
+
+#[derive(Clone)]
+///Owning iterator over bytes of `StrBuf`, created by `StrBuf::into_bytes_iter`.
+pub struct IntoBytes<const N: usize> {
+    buf: StrBuf<N>,
+    front: usize,
+    back: usize,
+}
+
+impl<const N: usize> IntoBytes<N> {
+    #[inline(always)]
+    pub(crate) const fn new(buf: StrBuf<N>) -> Self {
+        Self {
+            front: 0,
+            back: buf.len(),
+            buf,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns not yet iterated bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf.as_slice()[self.front..self.back]
+    }
+}
+
+impl<const N: usize> Iterator for IntoBytes<N> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let byte = self.buf.as_slice()[self.front];
+            self.front += 1;
+            Some(byte)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for IntoBytes<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.buf.as_slice()[self.back])
+        } else {
+            None
+        }
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for IntoBytes<N> {}
+impl<const N: usize> FusedIterator for IntoBytes<N> {}
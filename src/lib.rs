@@ -4,6 +4,10 @@
 //!
 //!- `serde` Enables serde serialization. In case of overflow, deserialize fails.
 //!- `ufmt-write` Enables ufmt `uWrite` implementation.
+//!- `const-mut` Enables `const` methods taking `&mut self`. Requires Rust 1.83.
+//!- `unicode-width` Enables display width aware `display_width` and `truncate_to_width`.
+//!- `unicode-segmentation` Enables grapheme aware `pop_grapheme` and `truncate_graphemes`.
+//!- `memchr` Enables `memchr` accelerated runtime search (`memchr`, `memrchr`, `memmem` and replacement methods).
 #![warn(missing_docs)]
 
 #![no_std]
@@ -12,16 +16,36 @@
 
 use core::{mem, slice, ptr, cmp, ops, hash, fmt, borrow};
 
+mod macros;
+
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(feature = "ufmt-write")]
 mod ufmt;
+mod transaction;
+pub use transaction::StrBufTransaction;
+mod utf8;
+pub use utf8::{Utf8Accumulator, PushBytes, Utf8PushError};
+mod iter;
+pub use iter::{IntoChars, IntoBytes};
+mod like;
+pub use like::StrBufLike;
+mod case;
+pub use case::CaseInsensitive;
 
 #[derive(Debug, Clone)]
 ///`StrBuf` conversion error
 pub enum StrBufError {
     ///Not enough space for string to be converted into `StrBuf`.
     Overflow,
+    ///Number of parts exceeds number of available slots.
+    TooManyParts,
+    ///Content is not valid UTF-8.
+    Utf8(core::str::Utf8Error),
+    ///Content is not ASCII.
+    NotAscii,
+    ///Wire data is malformed or truncated.
+    InvalidWire,
 }
 
 impl fmt::Display for StrBufError {
@@ -29,6 +53,10 @@ impl fmt::Display for StrBufError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             StrBufError::Overflow => fmt.write_str("Buffer overflow"),
+            StrBufError::TooManyParts => fmt.write_str("Too many parts"),
+            StrBufError::Utf8(error) => fmt.write_fmt(format_args!("Invalid UTF-8: {}", error)),
+            StrBufError::NotAscii => fmt.write_str("Non-ASCII content"),
+            StrBufError::InvalidWire => fmt.write_str("Malformed wire data"),
         }
     }
 }
@@ -51,6 +79,173 @@ pub const fn capacity(desired: usize) -> usize {
     }
 }
 
+#[inline]
+const fn is_ascii_bytes(bytes: &[u8]) -> bool {
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if !bytes[idx].is_ascii() {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+#[inline(always)]
+///Returns byte position of the first occurrence of `needle`, using `memchr` when enabled.
+fn find_str(text: &str, needle: &str) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memmem::find(text.as_bytes(), needle.as_bytes())
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        text.find(needle)
+    }
+}
+
+#[inline]
+///Determines whether `bytes` contain `needle` at `offset`.
+const fn bytes_eq_at(bytes: &[u8], offset: usize, needle: &[u8]) -> bool {
+    if offset > bytes.len() || bytes.len() - offset < needle.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < needle.len() {
+        if bytes[offset + idx] != needle[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+#[inline]
+///Resolves range bounds into `start..end` within `len`, panicking on invalid range.
+fn resolve_range<R: ops::RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        ops::Bound::Included(start) => *start,
+        ops::Bound::Excluded(start) => start.checked_add(1).expect("Range start overflow"),
+        ops::Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        ops::Bound::Included(end) => end.checked_add(1).expect("Range end overflow"),
+        ops::Bound::Excluded(end) => *end,
+        ops::Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "Range start is greater than end");
+    assert!(end <= len, "Range end is out of bounds");
+    (start, end)
+}
+
+#[inline]
+///Copies each part into fixed array of buffers, returning number of filled buffers.
+fn collect_parts<'a, I: Iterator<Item = &'a str>, const M: usize, const K: usize>(parts: I) -> Result<([StrBuf<M>; K], usize), StrBufError> {
+    let mut result = [StrBuf::<M>::new(); K];
+    let mut count = 0;
+    for part in parts {
+        match result.get_mut(count) {
+            Some(slot) => *slot = StrBuf::from_str_checked(part)?,
+            None => return Err(StrBufError::TooManyParts),
+        }
+        count += 1;
+    }
+
+    Ok((result, count))
+}
+
+#[inline]
+///Determines whether ASCII word boundary lies right before `cur`, considering previous and next bytes.
+///
+///Boundary is placed before upper case letter that follows lower case letter or digit, and before the last letter of upper case run that is followed by lower case letter (e.g. `HTTPServer`).
+const fn is_ascii_word_boundary(prev: u8, cur: u8, next: u8) -> bool {
+    cur.is_ascii_uppercase() && (prev.is_ascii_lowercase() || prev.is_ascii_digit() || (prev.is_ascii_uppercase() && next.is_ascii_lowercase()))
+}
+
+#[inline(always)]
+///Encodes character as UTF-8, returning bytes alongside with number of used bytes.
+const fn encode_utf8(ch: char) -> ([u8; 4], usize) {
+    let code = ch as u32;
+    let len = ch.len_utf8();
+    let bytes = match len {
+        1 => [code as u8, 0, 0, 0],
+        2 => [
+            0xC0 | (code >> 6) as u8,
+            0x80 | (code & 0x3F) as u8,
+            0,
+            0,
+        ],
+        3 => [
+            0xE0 | (code >> 12) as u8,
+            0x80 | ((code >> 6) & 0x3F) as u8,
+            0x80 | (code & 0x3F) as u8,
+            0,
+        ],
+        _ => [
+            0xF0 | (code >> 18) as u8,
+            0x80 | ((code >> 12) & 0x3F) as u8,
+            0x80 | ((code >> 6) & 0x3F) as u8,
+            0x80 | (code & 0x3F) as u8,
+        ],
+    };
+
+    (bytes, len)
+}
+
+#[inline(always)]
+///Decodes code point of valid UTF-8 character starting at `idx`, returning it alongside with number of used bytes.
+const fn decode_utf8(bytes: &[u8], idx: usize) -> (u32, usize) {
+    let lead = bytes[idx] as u32;
+    if lead < 0x80 {
+        (lead, 1)
+    } else if lead < 0xE0 {
+        ((lead & 0x1F) << 6 | (bytes[idx + 1] as u32 & 0x3F), 2)
+    } else if lead < 0xF0 {
+        ((lead & 0x0F) << 12 | (bytes[idx + 1] as u32 & 0x3F) << 6 | (bytes[idx + 2] as u32 & 0x3F), 3)
+    } else {
+        ((lead & 0x07) << 18 | (bytes[idx + 1] as u32 & 0x3F) << 12 | (bytes[idx + 2] as u32 & 0x3F) << 6 | (bytes[idx + 3] as u32 & 0x3F), 4)
+    }
+}
+
+#[inline(always)]
+#[allow(unknown_lints, unnecessary_transmutes)]
+///Converts code point into char in const context, as `char::from_u32_unchecked` is not `const` on MSRV.
+const unsafe fn char_from_u32_unchecked(code: u32) -> char {
+    mem::transmute::<u32, char>(code)
+}
+
+#[inline(always)]
+///Returns the largest char boundary of valid UTF-8 `bytes` that does not exceed `size`.
+const fn floor_char_boundary_of(bytes: &[u8], size: usize) -> usize {
+    if size >= bytes.len() {
+        return bytes.len();
+    }
+
+    let mut size = size;
+    while bytes[size] & 0xC0 == 0x80 {
+        size -= 1;
+    }
+    size
+}
+
+#[inline(always)]
+///Returns start of valid UTF-8 character that ends right before `end`.
+const fn prev_char_start(bytes: &[u8], end: usize) -> usize {
+    let mut idx = end - 1;
+    while bytes[idx] & 0xC0 == 0x80 {
+        idx -= 1;
+    }
+    idx
+}
+
+#[inline]
+///Checks whether code point has Unicode `White_Space` property, same as `char::is_whitespace`.
+const fn is_whitespace_code(code: u32) -> bool {
+    matches!(code, 0x09..=0x0D | 0x20 | 0x85 | 0xA0 | 0x1680 | 0x2000..=0x200A | 0x2028 | 0x2029 | 0x202F | 0x205F | 0x3000)
+}
+
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 ///Stack based string.
@@ -92,6 +287,9 @@ pub const fn capacity(desired: usize) -> usize {
 ///assert_eq!(text.push_str(" or maybe not"), 8); //Overflow!
 ///assert_eq!(text.as_str(), "test hello world or mayb");
 ///assert_eq!(text.push_str(" or maybe not"), 0); //Overflow, damn
+///assert!(text.try_push_str(" or maybe not").is_err()); //All or nothing
+///assert_eq!(text.as_str(), "test hello world or mayb");
+///assert_eq!(text.len(), MyStr::capacity());
 ///
 ///text.clear();
 ///assert_eq!(text.push_str(" or maybe not"), 13); //noice
@@ -141,6 +339,26 @@ impl<const N: usize> StrBuf<N> {
         }
     }
 
+    #[inline]
+    ///Creates new instance from supplied storage, validating embedded length and content.
+    ///
+    ///Returns error if length exceeds capacity or content is not valid UTF-8.
+    ///
+    ///It is unsafe, because validation reads storage:
+    ///first `Self::LEN_OFFSET` bytes and content region within embedded length must be initialized.
+    ///Storage obtained via `into_storage` satisfies this requirement.
+    pub const unsafe fn from_storage_validated(storage: [mem::MaybeUninit<u8>; N]) -> Result<Self, StrBufError> {
+        let result = Self::from_storage(storage);
+        if result.len() > Self::CAPACITY {
+            return Err(StrBufError::Overflow);
+        }
+
+        match core::str::from_utf8(result.as_slice()) {
+            Ok(_) => Ok(result),
+            Err(error) => Err(StrBufError::Utf8(error)),
+        }
+    }
+
     #[inline]
     ///Creates new instance from existing slice with panic on overflow
     pub const fn from_str(text: &str) -> Self {
@@ -168,251 +386,1997 @@ impl<const N: usize> StrBuf<N> {
         }
     }
 
-    #[inline(always)]
-    ///Reads byte at `idx`.
-    pub const unsafe fn get_unchecked(&self, idx: usize) -> u8 {
-        self.inner[Self::LEN_OFFSET + idx].assume_init()
-    }
-
     #[inline]
-    ///Reads byte at `idx`.
-    pub const fn get(&self, idx: usize) -> Option<u8> {
-        if idx < self.len() {
-            unsafe {
-                Some(self.get_unchecked(idx))
-            }
-        } else {
-            None
+    ///Creates new instance with single character.
+    ///
+    ///Panics if character does not fit capacity, failing compilation in const context.
+    pub const fn from_char(ch: char) -> Self {
+        let (bytes, len) = encode_utf8(ch);
+        if len > Self::CAPACITY {
+            panic!("Character cannot fit static storage");
         }
-    }
 
-    #[inline]
-    ///Returns pointer  to the beginning of underlying buffer
-    pub const fn as_ptr(&self) -> *const u8 {
-        unsafe {
-            self.inner.as_ptr().add(Self::LEN_OFFSET) as _
+        let mut storage = [mem::MaybeUninit::<u8>::uninit(); N];
+        let mut idx = 0;
+        while idx < len {
+            storage[Self::LEN_OFFSET + idx] = mem::MaybeUninit::new(bytes[idx]);
+            idx += 1;
         }
-    }
 
-    #[inline]
-    ///Returns pointer  to the beginning of underlying buffer
-    pub fn as_mut_ptr(&mut self) -> *mut u8 {
         unsafe {
-            self.inner.as_mut_ptr().add(Self::LEN_OFFSET) as *mut u8
+            Self::from_storage(storage).const_set_len(len)
         }
     }
 
-    #[inline]
-    ///Returns number of bytes left (not written yet)
-    pub const fn remaining(&self) -> usize {
-        Self::capacity() - self.len()
-    }
+    ///Creates new instance by encoding all `chars` in sequence.
+    ///
+    ///Returns error if encoded characters do not fit capacity.
+    pub fn from_chars(chars: &[char]) -> Result<Self, StrBufError> {
+        let len = chars.iter().map(|ch| ch.len_utf8()).sum::<usize>();
+        if len > Self::capacity() {
+            return Err(StrBufError::Overflow);
+        }
 
-    #[inline]
-    ///Returns reference to underlying storage as it is.
-    pub const fn as_storage(&self) -> &[mem::MaybeUninit<u8>; N] {
-        &self.inner
+        let mut result = Self::new();
+        unsafe {
+            let ptr = result.as_mut_ptr();
+            let mut cursor = 0;
+            for ch in chars {
+                let (bytes, ch_len) = encode_utf8(*ch);
+                ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.add(cursor), ch_len);
+                cursor += ch_len;
+            }
+            result.set_len(len);
+        }
+        Ok(result)
     }
 
     #[inline]
-    ///Returns reference to underlying storage as it is.
+    ///Creates new instance from ASCII only text.
     ///
-    ///To safely modify the storage, user must guarantee to write valid UTF-8
-    pub unsafe fn as_mut_storage(&mut self) -> &mut [mem::MaybeUninit<u8>; N] {
-        &mut self.inner
+    ///Panics if text contains non-ASCII characters or does not fit capacity, failing compilation in const context.
+    pub const fn from_ascii(text: &str) -> Self {
+        if !is_ascii_bytes(text.as_bytes()) {
+            panic!("Text contains non-ASCII characters");
+        }
+        Self::from_str(text)
     }
 
     #[inline]
-    ///Returns slice to already written data.
-    pub const fn as_slice(&self) -> &[u8] {
-        unsafe {
-            slice::from_raw_parts(self.as_ptr(), self.len())
+    ///Creates new instance from ASCII only text, returning error on non-ASCII characters or overflow.
+    pub const fn from_ascii_checked(text: &str) -> Result<Self, StrBufError> {
+        if !is_ascii_bytes(text.as_bytes()) {
+            Err(StrBufError::NotAscii)
+        } else {
+            Self::from_str_checked(text)
         }
     }
 
     #[inline]
-    ///Returns mutable slice to already written data.
+    ///Creates new instance with `text` repeated `count` times.
     ///
-    ///To safely modify the slice, user must guarantee to write valid UTF-8
-    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
-        slice::from_raw_parts_mut(self.as_mut_ptr(), self.len())
-    }
-
-    #[inline]
-    ///Returns mutable slice with unwritten parts of the buffer.
-    pub fn as_write_slice(&mut self) -> &mut [mem::MaybeUninit<u8>] {
-        let len = self.len();
-        &mut self.inner[Self::LEN_OFFSET + len..]
-    }
+    ///Panics if result does not fit capacity, failing compilation in const context.
+    pub const fn from_str_repeat(text: &str, count: usize) -> Self {
+        let len = match text.len().checked_mul(count) {
+            Some(len) if len <= Self::CAPACITY => len,
+            _ => panic!("Text cannot fit static storage"),
+        };
 
-    #[inline(always)]
-    ///Clears the content of buffer.
-    pub fn clear(&mut self) {
-        unsafe {
-            self.set_len(0);
+        let mut idx = 0;
+        let mut storage = [mem::MaybeUninit::<u8>::uninit(); N];
+        while idx < len {
+            storage[Self::LEN_OFFSET + idx] = mem::MaybeUninit::new(text.as_bytes()[idx % text.len()]);
+            idx += 1;
         }
-    }
 
-    #[inline(always)]
-    ///Returns empty self.
-    pub const fn empty(self) -> Self {
         unsafe {
-            self.const_set_len(0)
+            Self::from_storage(storage).const_set_len(len)
         }
     }
 
     #[inline]
-    ///Shortens the buffer, keeping the first `cursor` elements.
-    ///
-    ///Does nothing if new `cursor` is after current position.
-    ///
-    ///Unsafe as it is up to user to consider character boundary
-    pub unsafe fn truncate(&mut self, len: usize) {
-        if len < self.len() {
-            self.set_len(len);
+    ///Creates new instance with `text` repeated `count` times, returning error on overflow.
+    pub const fn from_str_repeat_checked(text: &str, count: usize) -> Result<Self, StrBufError> {
+        match text.len().checked_mul(count) {
+            Some(len) if len <= Self::CAPACITY => Ok(Self::from_str_repeat(text, count)),
+            _ => Err(StrBufError::Overflow),
         }
     }
 
     #[inline]
-    ///Returns buffer overall capacity.
-    pub const fn capacity() -> usize {
-        Self::CAPACITY
+    ///Creates new instance from bytes, replacing invalid UTF-8 sequences with `U+FFFD` and truncating on overflow.
+    pub fn from_bytes_lossy(bytes: &[u8]) -> Self {
+        let mut result = Self::new();
+        result.push_bytes_lossy(bytes);
+        result
     }
 
     #[inline]
-    ///Returns number of bytes written.
-    pub const fn len(&self) -> usize {
-        if N == 0 {
-            0
-        } else if N <= CAPACITY_U8 {
-            unsafe {
-                self.inner[0].assume_init() as _
-            }
-        } else if N <= CAPACITY_U16 {
-            unsafe {
-                u16::from_ne_bytes(*(self.inner.as_ptr() as *const [u8; mem::size_of::<u16>()])) as usize
-            }
-        } else {
-            unsafe {
-                usize::from_ne_bytes(*(self.inner.as_ptr() as *const [u8; mem::size_of::<usize>()]))
-            }
+    ///Creates new instance from format arguments.
+    ///
+    ///Returns error if formatted text does not fit capacity.
+    pub fn format(args: fmt::Arguments<'_>) -> Result<Self, StrBufError> {
+        let mut result = Self::new();
+        match fmt::Write::write_fmt(&mut result, args) {
+            Ok(()) => Ok(result),
+            Err(_) => Err(StrBufError::Overflow),
         }
     }
 
-    #[inline(always)]
-    ///Sets new length of the string.
-    const unsafe fn const_set_len(mut self, len: usize) -> Self {
-        if N == 0 {
-            //no length
-        } else if N <= CAPACITY_U8 {
-            self.inner[0] = mem::MaybeUninit::new(len as _);
-        } else if N <= CAPACITY_U16 {
-            let len = (len as u16).to_ne_bytes();
-            self.inner[0] = mem::MaybeUninit::new(len[0]);
-            self.inner[1] = mem::MaybeUninit::new(len[1]);
-        } else {
-            let len = len.to_ne_bytes();
-            let mut idx = 0;
-            loop {
-                self.inner[idx] = mem::MaybeUninit::new(len[idx]);
-                idx = idx.saturating_add(1);
-                if idx >= len.len() {
-                    break;
+    ///Creates new instance from format arguments, truncating on overflow.
+    ///
+    ///Result is always prefix of fully formatted text, ending on char boundary.
+    pub fn format_lossy(args: fmt::Arguments<'_>) -> Self {
+        struct LossyWriter<'a, const N: usize> {
+            buf: &'a mut StrBuf<N>,
+            is_full: bool,
+        }
+
+        impl<'a, const N: usize> fmt::Write for LossyWriter<'a, N> {
+            #[inline]
+            fn write_str(&mut self, text: &str) -> fmt::Result {
+                if !self.is_full {
+                    self.is_full = self.buf.push_str(text) < text.len();
                 }
+                Ok(())
             }
         }
 
-        self
-    }
-
-    #[inline(always)]
-    ///Sets new length of the string.
-    pub unsafe fn set_len(&mut self, len: usize) {
-        if N == 0 {
-            //No length
-        } else if N <= CAPACITY_U8 {
-            self.inner[0] = mem::MaybeUninit::new(len as _);
-        } else if N <= CAPACITY_U16 {
-            let len = (len as u16).to_ne_bytes();
-            self.inner[0] = mem::MaybeUninit::new(len[0]);
-            self.inner[1] = mem::MaybeUninit::new(len[1]);
-        } else {
-            let len = len.to_ne_bytes();
-            let ptr = self.inner.as_mut_ptr();
-            ptr::copy_nonoverlapping(len.as_ptr(), ptr as *mut _, mem::size_of::<usize>());
-        }
+        let mut result = Self::new();
+        let mut writer = LossyWriter {
+            buf: &mut result,
+            is_full: false,
+        };
+        let _ = fmt::Write::write_fmt(&mut writer, args);
+        result
     }
 
     #[inline]
-    ///Appends given string without any size checks
-    pub unsafe fn push_str_unchecked(&mut self, text: &str) {
-        ptr::copy_nonoverlapping(text.as_ptr(), self.as_mut_ptr().add(self.len()), text.len());
-        self.set_len(self.len().saturating_add(text.len()));
+    ///Creates new instance by formatting `value` using its `Display` implementation.
+    ///
+    ///Returns error if formatted value does not fit capacity.
+    pub fn from_display<T: fmt::Display + ?Sized>(value: &T) -> Result<Self, StrBufError> {
+        Self::format(format_args!("{}", value))
     }
 
     #[inline]
-    ///Appends given string, truncating on overflow, returning number of written bytes
-    pub fn push_str(&mut self, text: &str) -> usize {
-        let mut size = cmp::min(text.len(), self.remaining());
+    ///Creates new instance by formatting `value` using its `Debug` implementation.
+    ///
+    ///Returns error if formatted value does not fit capacity.
+    pub fn from_debug<T: fmt::Debug + ?Sized>(value: &T) -> Result<Self, StrBufError> {
+        Self::format(format_args!("{:?}", value))
+    }
 
-        #[cold]
-        fn shift_by_char_boundary(text: &str, mut size: usize) -> usize {
-            while !text.is_char_boundary(size) {
-                size -= 1;
-            }
-            size
+    ///Creates new instance by letting `cb` to write content directly into storage.
+    ///
+    ///`cb` receives whole content region and returns number of bytes written.
+    ///Region is zeroed beforehand, so claiming more bytes than actually written cannot expose uninitialized memory.
+    ///
+    ///Returns error if returned length exceeds capacity or written bytes are not valid UTF-8.
+    pub fn from_fn<F: FnOnce(&mut [mem::MaybeUninit<u8>]) -> usize>(cb: F) -> Result<Self, StrBufError> {
+        let mut result = Self::new();
+        let region = result.as_write_slice();
+        for byte in region.iter_mut() {
+            *byte = mem::MaybeUninit::new(0);
         }
 
-        if !text.is_char_boundary(size) {
-            //0 is always char boundary so 0 - 1 is impossible
-            size = shift_by_char_boundary(text, size - 1);
+        let len = cb(region);
+        if len > Self::capacity() {
+            return Err(StrBufError::Overflow);
         }
 
-        unsafe {
-            self.push_str_unchecked(&text[..size]);
+        let written = unsafe {
+            slice::from_raw_parts(result.as_ptr(), len)
+        };
+        match core::str::from_utf8(written) {
+            Ok(_) => unsafe {
+                result.set_len(len);
+                Ok(result)
+            },
+            Err(error) => Err(StrBufError::Utf8(error)),
         }
-        size
     }
 
     #[inline]
-    ///Appends given string, assuming it fits.
+    ///Creates new instance from UTF-8 bytes.
     ///
-    ///On overflow panics with index out of bounds.
-    pub const fn and(self, text: &str) -> Self {
-        unsafe {
-            self.and_unsafe(text.as_bytes())
+    ///Returns `StrBufError::Utf8` if bytes are not valid UTF-8 or `StrBufError::Overflow` if they do not fit capacity.
+    pub const fn from_utf8(bytes: &[u8]) -> Result<Self, StrBufError> {
+        match core::str::from_utf8(bytes) {
+            Ok(text) => Self::from_str_checked(text),
+            Err(error) => Err(StrBufError::Utf8(error)),
         }
     }
 
-   #[inline]
-    ///Unsafely appends given bytes, assuming valid utf-8.
+    #[inline(always)]
+    ///Creates new instance from UTF-8 bytes in const context.
     ///
-    ///On overflow panics with index out of bounds as `and`.
-    pub const unsafe fn and_unsafe(mut self, bytes: &[u8]) -> Self {
-        debug_assert!(self.remaining() >= bytes.len(), "Buffer overflow");
+    ///Performs full UTF-8 validation, rejecting overlong encodings, surrogates and code points above `U+10FFFF`.
+    ///Same as `from_utf8`, which is already `const`.
+    pub const fn from_utf8_const(bytes: &[u8]) -> Result<Self, StrBufError> {
+        Self::from_utf8(bytes)
+    }
 
-        let mut idx = 0;
-        let cursor = self.len();
-        while idx < bytes.len() {
-            self.inner[Self::LEN_OFFSET + cursor + idx] = mem::MaybeUninit::new(bytes[idx]);
-            idx += 1;
+    #[inline]
+    ///Creates new instance from UTF-8 bytes.
+    ///
+    ///Panics if bytes are not valid UTF-8 or do not fit capacity, failing compilation in const context.
+    pub const fn from_utf8_unwrap(bytes: &[u8]) -> Self {
+        match Self::from_utf8(bytes) {
+            Ok(result) => result,
+            Err(StrBufError::Utf8(_)) => panic!("Bytes are not valid UTF-8"),
+            Err(_) => panic!("Bytes cannot fit static storage"),
         }
-        self.const_set_len(cursor + bytes.len())
     }
 
-    #[inline(always)]
-    ///Access str from underlying storage
+    ///Creates new instance from characters, returning error as soon as character doesn't fit.
     ///
-    ///Returns empty if nothing has been written into buffer yet.
-    pub const fn as_str(&self) -> &str {
-        unsafe {
-            core::str::from_utf8_unchecked(self.as_slice())
+    ///Partially collected content is discarded on error.
+    pub fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self, StrBufError> {
+        let mut result = Self::new();
+        for ch in iter {
+            result.push(ch)?;
         }
+        Ok(result)
     }
 
-    #[inline(always)]
-    ///Modifies this string to convert all its characters into ASCII lower case equivalent
-    pub const fn into_ascii_lowercase(mut self) -> Self {
+    ///Creates new instance from string slices, returning error as soon as slice doesn't fit.
+    ///
+    ///Partially collected content is discarded on error.
+    pub fn try_from_str_iter<'a, I: IntoIterator<Item = &'a str>>(iter: I) -> Result<Self, StrBufError> {
+        let mut result = Self::new();
+        for text in iter {
+            result.try_push_str(text)?;
+        }
+        Ok(result)
+    }
+
+    ///Creates new instance by joining all `parts`, separated by `sep`.
+    ///
+    ///Returns error if result does not fit capacity.
+    pub fn join<I: IntoIterator>(parts: I, sep: &str) -> Result<Self, StrBufError> where I::Item: AsRef<str> {
+        let mut result = Self::new();
+        result.push_join(parts, sep)?;
+        Ok(result)
+    }
+
+    #[inline(always)]
+    ///Reads byte at `idx`.
+    pub const unsafe fn get_unchecked(&self, idx: usize) -> u8 {
+        self.inner[Self::LEN_OFFSET + idx].assume_init()
+    }
+
+    #[inline]
+    ///Reads byte at `idx`.
+    pub const fn get(&self, idx: usize) -> Option<u8> {
+        if idx < self.len() {
+            unsafe {
+                Some(self.get_unchecked(idx))
+            }
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns first character, usable in const context.
+    pub const fn first_char(&self) -> Option<char> {
+        let bytes = self.as_slice();
+        if bytes.is_empty() {
+            return None;
+        }
+
+        let (code, _) = decode_utf8(bytes, 0);
+        //Content is always valid UTF-8, hence code is valid char
+        unsafe {
+            Some(char_from_u32_unchecked(code))
+        }
+    }
+
+    #[inline]
+    ///Returns last character, usable in const context.
+    pub const fn last_char(&self) -> Option<char> {
+        let bytes = self.as_slice();
+        if bytes.is_empty() {
+            return None;
+        }
+
+        let (code, _) = decode_utf8(bytes, prev_char_start(bytes, bytes.len()));
+        //Content is always valid UTF-8, hence code is valid char
+        unsafe {
+            Some(char_from_u32_unchecked(code))
+        }
+    }
+
+    ///Converts range of character indexes into range of byte positions.
+    ///
+    ///Character index equal to number of characters refers to the end of content.
+    ///Returns `None` if range is out of bounds or its start exceeds its end.
+    pub fn char_range_to_byte_range(&self, chars: ops::Range<usize>) -> Option<ops::Range<usize>> {
+        if chars.start > chars.end {
+            return None;
+        }
+
+        let mut boundaries = self.char_indices().map(|(idx, _)| idx).chain(core::iter::once(self.len()));
+        let start = boundaries.nth(chars.start)?;
+        let end = match chars.end - chars.start {
+            0 => start,
+            count => boundaries.nth(count - 1)?,
+        };
+        Some(start..end)
+    }
+
+    ///Converts byte position into character index.
+    ///
+    ///Position equal to `len()` is converted into number of characters.
+    ///Returns `None` if position is out of bounds or lies within character.
+    pub fn byte_to_char_index(&self, byte_idx: usize) -> Option<usize> {
+        if byte_idx > self.len() || !self.is_char_boundary(byte_idx) {
+            return None;
+        }
+
+        Some(self.as_slice()[..byte_idx].iter().filter(|byte| **byte & 0xC0 != 0x80).count())
+    }
+
+    #[inline]
+    ///Returns sub-string within byte range `start..end`, usable in const context.
+    ///
+    ///Same as `str::get`, returns `None` if range is out of bounds, `start` exceeds `end` or either of them doesn't lie on char boundary.
+    pub const fn get_str(&self, start: usize, end: usize) -> Option<&str> {
+        if start > end || !self.is_boundary(start) || !self.is_boundary(end) {
+            return None;
+        }
+
+        unsafe {
+            Some(core::str::from_utf8_unchecked(slice::from_raw_parts(self.as_ptr().add(start), end - start)))
+        }
+    }
+
+    #[inline]
+    ///Returns character starting at byte position `byte_idx`.
+    ///
+    ///Returns `None` if `byte_idx` is out of bounds or lies within character.
+    pub const fn char_at(&self, byte_idx: usize) -> Option<char> {
+        if byte_idx >= self.len() || !self.is_boundary(byte_idx) {
+            return None;
+        }
+
+        let (code, _) = decode_utf8(self.as_slice(), byte_idx);
+        //Content is always valid UTF-8, hence code is valid char
+        unsafe {
+            Some(char_from_u32_unchecked(code))
+        }
+    }
+
+    #[inline]
+    ///Returns the closest char boundary not greater than `idx`.
+    ///
+    ///Returns `len()` if `idx` is past the end.
+    pub const fn floor_char_boundary(&self, idx: usize) -> usize {
+        floor_char_boundary_of(self.as_slice(), idx)
+    }
+
+    #[inline]
+    ///Returns the closest char boundary not less than `idx`.
+    ///
+    ///Returns `len()` if `idx` is past the end.
+    pub const fn ceil_char_boundary(&self, idx: usize) -> usize {
+        let bytes = self.as_slice();
+        if idx >= bytes.len() {
+            return bytes.len();
+        }
+
+        let mut idx = idx;
+        while idx < bytes.len() && bytes[idx] & 0xC0 == 0x80 {
+            idx += 1;
+        }
+        idx
+    }
+
+    #[cfg(feature = "unicode-width")]
+    ///Returns display width of content as sum of characters widths.
+    ///
+    ///Wide characters count as 2, zero width characters (e.g. combining marks) and control characters as 0.
+    pub fn display_width(&self) -> usize {
+        use unicode_width::UnicodeWidthChar;
+
+        self.chars().map(|ch| ch.width().unwrap_or(0)).sum()
+    }
+
+    #[cfg(feature = "unicode-width")]
+    ///Shortens the buffer on char boundary, so that `display_width()` does not exceed `max_width`.
+    ///
+    ///Zero width characters following the last kept character are kept.
+    pub fn truncate_to_width(&mut self, max_width: usize) {
+        use unicode_width::UnicodeWidthChar;
+
+        let mut width = 0;
+        let mut new_len = self.len();
+        for (idx, ch) in self.char_indices() {
+            width += ch.width().unwrap_or(0);
+            if width > max_width {
+                new_len = idx;
+                break;
+            }
+        }
+
+        unsafe {
+            self.truncate(new_len);
+        }
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    ///Removes the last extended grapheme cluster, returning it.
+    ///
+    ///Returned string refers to removed bytes, which remain in spare capacity until overwritten.
+    pub fn pop_grapheme(&mut self) -> Option<&str> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let (start, grapheme) = self.grapheme_indices(true).next_back()?;
+        let grapheme_len = grapheme.len();
+        unsafe {
+            self.set_len(start);
+            Some(core::str::from_utf8_unchecked(slice::from_raw_parts(self.as_ptr().add(start), grapheme_len)))
+        }
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    ///Shortens the buffer, keeping at most first `count` extended grapheme clusters.
+    pub fn truncate_graphemes(&mut self, count: usize) {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let new_len = match self.grapheme_indices(true).nth(count) {
+            Some((idx, _)) => idx,
+            None => return,
+        };
+        unsafe {
+            self.set_len(new_len);
+        }
+    }
+
+    ///Returns character at index `char_idx` (counted in characters, not bytes), usable in const context.
+    pub const fn get_char(&self, char_idx: usize) -> Option<char> {
+        let bytes = self.as_slice();
+        let mut cursor = 0;
+        let mut remaining = char_idx;
+        while cursor < bytes.len() {
+            let (code, ch_len) = decode_utf8(bytes, cursor);
+            if remaining == 0 {
+                //Content is always valid UTF-8, hence code is valid char
+                return unsafe {
+                    Some(char_from_u32_unchecked(code))
+                };
+            }
+            remaining -= 1;
+            cursor += ch_len;
+        }
+        None
+    }
+
+    #[inline]
+    ///Returns number of characters, usable in const context.
+    pub const fn char_count(&self) -> usize {
+        let bytes = self.as_slice();
+        let mut count = 0;
+        let mut idx = 0;
+        while idx < bytes.len() {
+            if bytes[idx] & 0xC0 != 0x80 {
+                count += 1;
+            }
+            idx += 1;
+        }
+        count
+    }
+
+    #[inline]
+    ///Returns index of the first occurrence of `byte`, using `memchr` when enabled.
+    ///
+    ///Runtime counterpart of `find_byte`, which being `const` always performs naive scan.
+    pub fn memchr(&self, byte: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        {
+            memchr::memchr(byte, self.as_slice())
+        }
+        #[cfg(not(feature = "memchr"))]
+        {
+            self.as_slice().iter().position(|item| *item == byte)
+        }
+    }
+
+    #[inline]
+    ///Returns index of the last occurrence of `byte`, using `memchr` when enabled.
+    ///
+    ///Runtime counterpart of `rfind_byte`, which being `const` always performs naive scan.
+    pub fn memrchr(&self, byte: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        {
+            memchr::memrchr(byte, self.as_slice())
+        }
+        #[cfg(not(feature = "memchr"))]
+        {
+            self.as_slice().iter().rposition(|item| *item == byte)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns byte index of the first occurrence of `needle`, using `memchr` when enabled.
+    ///
+    ///Runtime counterpart of `const_find`, which being `const` always performs naive search.
+    pub fn memmem(&self, needle: &str) -> Option<usize> {
+        find_str(self.as_str(), needle)
+    }
+
+    #[inline]
+    ///Returns index of the first occurrence of `byte`.
+    pub const fn find_byte(&self, byte: u8) -> Option<usize> {
+        let bytes = self.as_slice();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            if bytes[idx] == byte {
+                return Some(idx);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    #[inline]
+    ///Returns index of the last occurrence of `byte`.
+    pub const fn rfind_byte(&self, byte: u8) -> Option<usize> {
+        let bytes = self.as_slice();
+        let mut idx = bytes.len();
+        while idx > 0 {
+            idx -= 1;
+            if bytes[idx] == byte {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    ///Checks whether `idx` lies on char boundary within written data.
+    const fn is_boundary(&self, idx: usize) -> bool {
+        let len = self.len();
+        if idx == 0 || idx == len {
+            true
+        } else if idx > len {
+            false
+        } else {
+            unsafe {
+                //Continuation bytes are `0b10xx_xxxx`
+                self.get_unchecked(idx) & 0xC0 != 0x80
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns pointer  to the beginning of underlying buffer
+    pub const fn as_ptr(&self) -> *const u8 {
+        unsafe {
+            self.inner.as_ptr().add(Self::LEN_OFFSET) as _
+        }
+    }
+
+    #[inline]
+    ///Returns pointer  to the beginning of underlying buffer
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        unsafe {
+            self.inner.as_mut_ptr().add(Self::LEN_OFFSET) as *mut u8
+        }
+    }
+
+    #[inline]
+    ///Returns number of bytes left (not written yet)
+    pub const fn remaining(&self) -> usize {
+        Self::capacity() - self.len()
+    }
+
+    #[inline(always)]
+    ///Returns buffer overall capacity, same as `capacity()`, but callable on instance.
+    pub const fn capacity_of(&self) -> usize {
+        Self::capacity()
+    }
+
+    #[inline]
+    ///Asserts that `additional` bytes can be written, mirroring `String::reserve`.
+    ///
+    ///Buffer never grows, so this only checks feasibility.
+    ///
+    ///Panics if `additional` exceeds `remaining()`.
+    pub fn reserve(&mut self, additional: usize) {
+        if self.try_reserve(additional).is_err() {
+            panic!("Cannot reserve {} bytes with only {} remaining", additional, self.remaining());
+        }
+    }
+
+    #[inline]
+    ///Checks that `additional` bytes can be written, mirroring `String::try_reserve`.
+    ///
+    ///Buffer never grows, so this only checks feasibility.
+    ///
+    ///Returns error if `additional` exceeds `remaining()`.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), StrBufError> {
+        if additional <= self.remaining() {
+            Ok(())
+        } else {
+            Err(StrBufError::Overflow)
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of bytes left (not written yet), same as `remaining`.
+    pub const fn spare_capacity(&self) -> usize {
+        self.remaining()
+    }
+
+    #[inline(always)]
+    ///Returns whether nothing is written yet, i.e. `len() == 0`.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    ///Returns whether no more bytes can be written, i.e. `remaining() == 0`.
+    pub const fn is_full(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    #[inline(always)]
+    ///Returns iterator over content encoded as UTF-16 code units.
+    pub fn encode_utf16(&self) -> impl Iterator<Item = u16> + '_ {
+        self.as_str().encode_utf16()
+    }
+
+    ///Encodes content as UTF-16 into zero padded array, returning it alongside with number of written code units.
+    ///
+    ///If `nul_terminated` is set, NUL code unit is written after content and included in returned count.
+    ///
+    ///Returns error if encoded content (including terminator) does not fit array.
+    pub fn to_utf16<const M: usize>(&self, nul_terminated: bool) -> Result<([u16; M], usize), StrBufError> {
+        let mut result = [0u16; M];
+        let mut len = 0;
+        for unit in self.encode_utf16() {
+            match result.get_mut(len) {
+                Some(slot) => *slot = unit,
+                None => return Err(StrBufError::Overflow),
+            }
+            len += 1;
+        }
+
+        if nul_terminated {
+            if len == M {
+                return Err(StrBufError::Overflow);
+            }
+            //Array is zeroed already
+            len += 1;
+        }
+        Ok((result, len))
+    }
+
+    ///Copies content into zero padded array, returning it alongside with content length.
+    ///
+    ///Panics if content does not fit array, failing compilation in const context.
+    pub const fn to_array<const M: usize>(&self) -> ([u8; M], usize) {
+        let bytes = self.as_slice();
+        if bytes.len() > M {
+            panic!("Content cannot fit array");
+        }
+
+        let mut result = [0u8; M];
+        let mut idx = 0;
+        while idx < bytes.len() {
+            result[idx] = bytes[idx];
+            idx += 1;
+        }
+        (result, bytes.len())
+    }
+
+    #[inline]
+    ///Copies content into zero padded array, returning it alongside with content length.
+    ///
+    ///Returns error if content does not fit array.
+    pub const fn to_array_checked<const M: usize>(&self) -> Result<([u8; M], usize), StrBufError> {
+        if self.len() > M {
+            Err(StrBufError::Overflow)
+        } else {
+            Ok(self.to_array())
+        }
+    }
+
+    ///Returns number of bytes required by `write_wire`.
+    pub const fn wire_len(&self) -> usize {
+        let mut len = self.len();
+        let mut size = 1;
+        while len >= 0x80 {
+            len >>= 7;
+            size += 1;
+        }
+        size + self.len()
+    }
+
+    ///Serializes content into endianness independent wire format, returning number of written bytes.
+    ///
+    ///Format is length encoded as unsigned LEB128 varint (7 bits per byte, least significant group first, high bit set on every byte except the last),
+    ///followed by content bytes.
+    ///
+    ///Returns error if `out` is shorter than `wire_len()`, leaving it unchanged.
+    pub fn write_wire(&self, out: &mut [u8]) -> Result<usize, StrBufError> {
+        let wire_len = self.wire_len();
+        if out.len() < wire_len {
+            return Err(StrBufError::Overflow);
+        }
+
+        let mut len = self.len();
+        let mut cursor = 0;
+        while len >= 0x80 {
+            out[cursor] = (len as u8 & 0x7F) | 0x80;
+            len >>= 7;
+            cursor += 1;
+        }
+        out[cursor] = len as u8;
+        cursor += 1;
+
+        out[cursor..wire_len].copy_from_slice(self.as_slice());
+        Ok(wire_len)
+    }
+
+    ///Deserializes content from wire format produced by `write_wire`.
+    ///
+    ///Bytes following encoded content are ignored.
+    ///
+    ///Returns `StrBufError::InvalidWire` if data is truncated or length is malformed,
+    ///`StrBufError::Overflow` if content exceeds capacity and `StrBufError::Utf8` if content is not valid UTF-8.
+    pub fn from_wire(data: &[u8]) -> Result<Self, StrBufError> {
+        let mut len = 0usize;
+        let mut shift = 0;
+        let mut cursor = 0;
+        loop {
+            let byte = match data.get(cursor) {
+                Some(byte) => *byte,
+                None => return Err(StrBufError::InvalidWire),
+            };
+            cursor += 1;
+
+            let group = (byte & 0x7F) as usize;
+            if shift >= usize::BITS || (group << shift) >> shift != group {
+                return Err(StrBufError::InvalidWire);
+            }
+            len |= group << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        if len > Self::capacity() {
+            return Err(StrBufError::Overflow);
+        }
+        match data.get(cursor..cursor + len) {
+            Some(content) => Self::from_utf8(content),
+            None => Err(StrBufError::InvalidWire),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns owning iterator over content bytes.
+    pub const fn into_bytes_iter(self) -> IntoBytes<N> {
+        IntoBytes::new(self)
+    }
+
+    #[inline(always)]
+    ///Returns underlying storage as it is, including length header.
+    ///
+    ///Instance can be restored via `from_storage` or `from_storage_validated`.
+    pub const fn into_storage(self) -> [mem::MaybeUninit<u8>; N] {
+        self.inner
+    }
+
+    #[inline]
+    ///Returns reference to underlying storage as it is.
+    pub const fn as_storage(&self) -> &[mem::MaybeUninit<u8>; N] {
+        &self.inner
+    }
+
+    #[inline]
+    ///Returns reference to underlying storage as it is.
+    ///
+    ///To safely modify the storage, user must guarantee to write valid UTF-8
+    pub unsafe fn as_mut_storage(&mut self) -> &mut [mem::MaybeUninit<u8>; N] {
+        &mut self.inner
+    }
+
+    #[inline]
+    ///Returns slice to already written data.
+    pub const fn as_slice(&self) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(self.as_ptr(), self.len())
+        }
+    }
+
+    #[inline]
+    ///Returns mutable slice to already written data.
+    ///
+    ///To safely modify the slice, user must guarantee to write valid UTF-8
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        slice::from_raw_parts_mut(self.as_mut_ptr(), self.len())
+    }
+
+    #[inline]
+    ///Returns mutable reference to already written data as `str`.
+    ///
+    ///It is safe as `str` methods cannot produce invalid UTF-8.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe {
+            core::str::from_utf8_unchecked_mut(self.as_mut_slice())
+        }
+    }
+
+    #[inline]
+    ///Returns mutable slice with unwritten parts of the buffer.
+    pub fn as_write_slice(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+        let len = self.len();
+        &mut self.inner[Self::LEN_OFFSET + len..]
+    }
+
+    ///Appends content written by `cb` directly into unwritten part of the buffer.
+    ///
+    ///`cb` receives whole unwritten region and returns number of bytes written at its start.
+    ///Region is zeroed beforehand, so claiming more bytes than actually written cannot expose uninitialized memory.
+    ///
+    ///Returns `StrBufError::Overflow` if returned length exceeds `remaining()`, or `StrBufError::Utf8` if written bytes are not valid UTF-8.
+    ///In latter case `valid_up_to()` is offset within written bytes and length is left unchanged.
+    pub fn commit_from<F: FnOnce(&mut [u8]) -> usize>(&mut self, cb: F) -> Result<(), StrBufError> {
+        let len = self.len();
+        let region = self.as_write_slice();
+        for byte in region.iter_mut() {
+            *byte = mem::MaybeUninit::new(0);
+        }
+
+        let region = unsafe {
+            slice::from_raw_parts_mut(region.as_mut_ptr() as *mut u8, region.len())
+        };
+        let written = cb(region);
+        if written > region.len() {
+            return Err(StrBufError::Overflow);
+        }
+
+        //Existing content always ends on char boundary, so written bytes must be valid on their own
+        match core::str::from_utf8(&region[..written]) {
+            Ok(_) => unsafe {
+                self.set_len(len + written);
+                Ok(())
+            },
+            Err(error) => Err(StrBufError::Utf8(error)),
+        }
+    }
+
+    #[inline(always)]
+    ///Clears the content of buffer.
+    pub fn clear(&mut self) {
+        unsafe {
+            self.set_len(0);
+        }
+    }
+
+    #[inline(always)]
+    ///Returns empty self.
+    pub const fn empty(self) -> Self {
+        unsafe {
+            self.const_set_len(0)
+        }
+    }
+
+    #[inline]
+    ///Shortens the buffer, keeping the first `cursor` elements.
+    ///
+    ///Does nothing if new `cursor` is after current position.
+    ///
+    ///Unsafe as it is up to user to consider character boundary.
+    ///Prefer `truncate_str` unless character boundary is already known.
+    pub unsafe fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.set_len(len);
+        }
+    }
+
+    #[inline]
+    ///Shortens the buffer, keeping at most first `new_len` bytes.
+    ///
+    ///Does nothing if `new_len` is after current position.
+    ///
+    ///If `new_len` lies within character, it is rounded down to the beginning of this character.
+    pub fn truncate_str(&mut self, mut new_len: usize) {
+        if new_len < self.len() {
+            while !self.is_char_boundary(new_len) {
+                new_len -= 1;
+            }
+            unsafe {
+                self.set_len(new_len);
+            }
+        }
+    }
+
+    ///Shortens the buffer to at most `max_chars` characters, replacing removed tail with `ellipsis`.
+    ///
+    ///Does nothing if buffer already contains `max_chars` characters or less.
+    ///
+    ///Result never exceeds original length in bytes: if `ellipsis` is longer than removed part, more characters are removed to fit it.
+    ///If `ellipsis` cannot fit at all, buffer is simply truncated to `max_chars` characters.
+    pub fn truncate_with_ellipsis(&mut self, max_chars: usize, ellipsis: &str) {
+        let len = self.len();
+        let mut max_chars_end = len;
+        let mut char_count = 0;
+        for (idx, _) in self.char_indices() {
+            if char_count == max_chars {
+                max_chars_end = idx;
+            }
+            char_count += 1;
+        }
+
+        if char_count <= max_chars {
+            return;
+        }
+
+        let ellipsis_chars = ellipsis.chars().count();
+        if ellipsis_chars <= max_chars && ellipsis.len() <= len {
+            let keep_chars = max_chars - ellipsis_chars;
+            let mut keep_end = match self.char_indices().nth(keep_chars) {
+                Some((idx, _)) => idx,
+                None => len,
+            };
+
+            while keep_end + ellipsis.len() > len {
+                keep_end -= 1;
+                while !self.is_char_boundary(keep_end) {
+                    keep_end -= 1;
+                }
+            }
+
+            unsafe {
+                self.replace_bytes_unchecked(keep_end, len, ellipsis.as_bytes());
+            }
+        } else {
+            unsafe {
+                self.set_len(max_chars_end);
+            }
+        }
+    }
+
+    #[inline]
+    ///Removes first `count` bytes, shifting rest of the content to the beginning.
+    ///
+    ///Clears buffer if `count` is equal or greater than current length.
+    ///
+    ///If `count` lies within character, it is rounded down to the beginning of this character, hence this character is kept.
+    pub fn truncate_front(&mut self, mut count: usize) {
+        if count >= self.len() {
+            self.clear();
+        } else if count > 0 {
+            while !self.is_char_boundary(count) {
+                count -= 1;
+            }
+            unsafe {
+                self.replace_bytes_unchecked(0, count, &[]);
+            }
+        }
+    }
+
+    #[inline(always)]
+    ///Returns checkpoint of current content, which can be used to discard everything written after it via `rollback`.
+    pub const fn checkpoint(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    ///Discards everything written after `checkpoint`.
+    ///
+    ///Only shortens the buffer, so content modified before `checkpoint` is not restored.
+    ///Does nothing if `checkpoint` is after current position.
+    pub fn rollback(&mut self, checkpoint: usize) {
+        self.truncate_str(checkpoint);
+    }
+
+    #[inline(always)]
+    ///Starts transaction, returning guard that truncates back to the starting length on drop unless committed.
+    ///
+    ///Guard behaves as `rollback` with checkpoint taken at the start of transaction, so edits to earlier content are not undone.
+    pub fn transaction(&mut self) -> StrBufTransaction<'_, N> {
+        StrBufTransaction::new(self)
+    }
+
+    #[inline]
+    ///Returns buffer overall capacity.
+    pub const fn capacity() -> usize {
+        Self::CAPACITY
+    }
+
+    #[inline]
+    ///Returns number of bytes written.
+    pub const fn len(&self) -> usize {
+        if N == 0 {
+            0
+        } else if N <= CAPACITY_U8 {
+            unsafe {
+                self.inner[0].assume_init() as _
+            }
+        } else if N <= CAPACITY_U16 {
+            unsafe {
+                u16::from_ne_bytes(*(self.inner.as_ptr() as *const [u8; mem::size_of::<u16>()])) as usize
+            }
+        } else {
+            unsafe {
+                usize::from_ne_bytes(*(self.inner.as_ptr() as *const [u8; mem::size_of::<usize>()]))
+            }
+        }
+    }
+
+    #[cfg(feature = "const-mut")]
+    #[inline(always)]
+    ///Sets new length of the string in place.
+    const unsafe fn const_set_len_mut(&mut self, len: usize) {
+        if N == 0 {
+            //no length
+        } else if N <= CAPACITY_U8 {
+            self.inner[0] = mem::MaybeUninit::new(len as _);
+        } else if N <= CAPACITY_U16 {
+            let len = (len as u16).to_ne_bytes();
+            self.inner[0] = mem::MaybeUninit::new(len[0]);
+            self.inner[1] = mem::MaybeUninit::new(len[1]);
+        } else {
+            let len = len.to_ne_bytes();
+            let mut idx = 0;
+            while idx < len.len() {
+                self.inner[idx] = mem::MaybeUninit::new(len[idx]);
+                idx += 1;
+            }
+        }
+    }
+
+    #[cfg(feature = "const-mut")]
+    ///Appends given string in const context, truncating on overflow, returning number of written bytes.
+    ///
+    ///Same as `push_str`, but usable in const context.
+    pub const fn push_str_const(&mut self, text: &str) -> usize {
+        let bytes = text.as_bytes();
+        let cursor = self.len();
+        let size = floor_char_boundary_of(bytes, self.remaining());
+
+        let mut idx = 0;
+        while idx < size {
+            self.inner[Self::LEN_OFFSET + cursor + idx] = mem::MaybeUninit::new(bytes[idx]);
+            idx += 1;
+        }
+        unsafe {
+            self.const_set_len_mut(cursor + size);
+        }
+        size
+    }
+
+    #[cfg(feature = "const-mut")]
+    #[inline(always)]
+    ///Clears the content of buffer in const context.
+    pub const fn clear_const(&mut self) {
+        unsafe {
+            self.const_set_len_mut(0);
+        }
+    }
+
+    #[inline(always)]
+    ///Sets new length of the string.
+    const unsafe fn const_set_len(mut self, len: usize) -> Self {
+        if N == 0 {
+            //no length
+        } else if N <= CAPACITY_U8 {
+            self.inner[0] = mem::MaybeUninit::new(len as _);
+        } else if N <= CAPACITY_U16 {
+            let len = (len as u16).to_ne_bytes();
+            self.inner[0] = mem::MaybeUninit::new(len[0]);
+            self.inner[1] = mem::MaybeUninit::new(len[1]);
+        } else {
+            let len = len.to_ne_bytes();
+            let mut idx = 0;
+            loop {
+                self.inner[idx] = mem::MaybeUninit::new(len[idx]);
+                idx = idx.saturating_add(1);
+                if idx >= len.len() {
+                    break;
+                }
+            }
+        }
+
+        self
+    }
+
+    #[inline(always)]
+    ///Sets new length of the string.
+    pub unsafe fn set_len(&mut self, len: usize) {
+        if N == 0 {
+            //No length
+        } else if N <= CAPACITY_U8 {
+            self.inner[0] = mem::MaybeUninit::new(len as _);
+        } else if N <= CAPACITY_U16 {
+            let len = (len as u16).to_ne_bytes();
+            self.inner[0] = mem::MaybeUninit::new(len[0]);
+            self.inner[1] = mem::MaybeUninit::new(len[1]);
+        } else {
+            let len = len.to_ne_bytes();
+            let ptr = self.inner.as_mut_ptr();
+            ptr::copy_nonoverlapping(len.as_ptr(), ptr as *mut _, mem::size_of::<usize>());
+        }
+    }
+
+    #[inline]
+    ///Appends given string without any size checks
+    pub unsafe fn push_str_unchecked(&mut self, text: &str) {
+        ptr::copy_nonoverlapping(text.as_ptr(), self.as_mut_ptr().add(self.len()), text.len());
+        self.set_len(self.len().saturating_add(text.len()));
+    }
+
+    ///Appends given bytes, replacing invalid UTF-8 sequences with `U+FFFD`, returning number of written bytes.
+    ///
+    ///Truncates on overflow, never writing partial replacement character.
+    pub fn push_bytes_lossy(&mut self, mut bytes: &[u8]) -> usize {
+        const REPLACEMENT: &str = "\u{FFFD}";
+        let mut written = 0;
+
+        loop {
+            match core::str::from_utf8(bytes) {
+                Ok(text) => return written + self.push_str(text),
+                Err(error) => {
+                    let valid_up_to = error.valid_up_to();
+                    let valid = unsafe {
+                        core::str::from_utf8_unchecked(&bytes[..valid_up_to])
+                    };
+                    let size = self.push_str(valid);
+                    written += size;
+                    if size < valid_up_to {
+                        return written;
+                    }
+
+                    let size = self.push_str(REPLACEMENT);
+                    written += size;
+                    if size == 0 {
+                        return written;
+                    }
+
+                    match error.error_len() {
+                        Some(invalid_len) => bytes = &bytes[valid_up_to + invalid_len..],
+                        None => return written,
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    ///Appends given string, truncating on overflow, returning number of written bytes
+    pub fn push_str(&mut self, text: &str) -> usize {
+        let mut size = cmp::min(text.len(), self.remaining());
+
+        #[cold]
+        fn shift_by_char_boundary(text: &str, mut size: usize) -> usize {
+            while !text.is_char_boundary(size) {
+                size -= 1;
+            }
+            size
+        }
+
+        if !text.is_char_boundary(size) {
+            //0 is always char boundary so 0 - 1 is impossible
+            size = shift_by_char_boundary(text, size - 1);
+        }
+
+        unsafe {
+            self.push_str_unchecked(&text[..size]);
+        }
+        size
+    }
+
+    #[inline]
+    ///Appends all `parts` one after another.
+    ///
+    ///Either all parts are written or buffer is left unchanged.
+    pub fn push_all(&mut self, parts: &[&str]) -> Result<(), StrBufError> {
+        let mut size = 0usize;
+        for part in parts {
+            size = size.saturating_add(part.len());
+        }
+
+        if self.remaining() < size {
+            Err(StrBufError::Overflow)
+        } else {
+            for part in parts {
+                unsafe {
+                    self.push_str_unchecked(part);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[inline(always)]
+    ///Appends given string followed by `\n`.
+    ///
+    ///Either whole line is written or buffer is left unchanged.
+    pub fn push_line(&mut self, text: &str) -> Result<(), StrBufError> {
+        self.push_all(&[text, "\n"])
+    }
+
+    #[inline(always)]
+    ///Appends given string followed by `\r\n`.
+    ///
+    ///Either whole line is written or buffer is left unchanged.
+    pub fn push_line_crlf(&mut self, text: &str) -> Result<(), StrBufError> {
+        self.push_all(&[text, "\r\n"])
+    }
+
+    #[inline]
+    ///Replaces whole content with `text`.
+    ///
+    ///Returns error on overflow, leaving previous content intact.
+    pub fn assign(&mut self, text: &str) -> Result<(), StrBufError> {
+        if text.len() > Self::capacity() {
+            Err(StrBufError::Overflow)
+        } else {
+            unsafe {
+                self.set_len(0);
+                self.push_str_unchecked(text);
+            }
+            Ok(())
+        }
+    }
+
+    #[inline(always)]
+    ///Replaces whole content with content of `other` buffer.
+    ///
+    ///Returns error on overflow, leaving previous content intact.
+    pub fn copy_from<const M: usize>(&mut self, other: &StrBuf<M>) -> Result<(), StrBufError> {
+        self.assign(other.as_str())
+    }
+
+    ///Exchanges content with `other` buffer of possibly different capacity.
+    ///
+    ///Returns error if either content does not fit capacity of other buffer, leaving both unchanged.
+    pub fn swap_with<const M: usize>(&mut self, other: &mut StrBuf<M>) -> Result<(), StrBufError> {
+        let len = self.len();
+        let other_len = other.len();
+        if len > StrBuf::<M>::capacity() || other_len > Self::capacity() {
+            return Err(StrBufError::Overflow);
+        }
+
+        let common_len = cmp::min(len, other_len);
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let other_ptr = other.as_mut_ptr();
+            ptr::swap_nonoverlapping(ptr, other_ptr, common_len);
+            if len > other_len {
+                ptr::copy_nonoverlapping(ptr.add(common_len) as *const _, other_ptr.add(common_len), len - common_len);
+            } else {
+                ptr::copy_nonoverlapping(other_ptr.add(common_len) as *const _, ptr.add(common_len), other_len - common_len);
+            }
+            self.set_len(other_len);
+            other.set_len(len);
+        }
+        Ok(())
+    }
+
+    ///Appends formatted text.
+    ///
+    ///Returns error if formatted text does not fit, leaving content unchanged.
+    pub fn append_format(&mut self, args: fmt::Arguments<'_>) -> Result<(), StrBufError> {
+        let checkpoint = self.checkpoint();
+        match fmt::Write::write_fmt(self, args) {
+            Ok(()) => Ok(()),
+            Err(_) => {
+                self.rollback(checkpoint);
+                Err(StrBufError::Overflow)
+            }
+        }
+    }
+
+    #[inline]
+    ///Appends given string, truncating on overflow, returning part of `text` that has not been written.
+    pub fn push_str_remainder<'t>(&mut self, text: &'t str) -> &'t str {
+        let size = self.push_str(text);
+        &text[size..]
+    }
+
+    #[inline]
+    ///Appends given string, returning error on overflow.
+    ///
+    ///Either whole string is written or buffer is left unchanged.
+    pub fn try_push_str(&mut self, text: &str) -> Result<(), StrBufError> {
+        if self.remaining() < text.len() {
+            Err(StrBufError::Overflow)
+        } else {
+            unsafe {
+                self.push_str_unchecked(text);
+            }
+            Ok(())
+        }
+    }
+
+    ///Appends given string repeated `count` times, truncating on overflow, returning number of written bytes
+    pub fn push_repeat(&mut self, text: &str, count: usize) -> usize {
+        if text.is_empty() {
+            return 0;
+        }
+
+        let size = cmp::min(text.len().saturating_mul(count), self.remaining());
+        let full_count = size / text.len();
+        let mut partial_size = size % text.len();
+        while !text.is_char_boundary(partial_size) {
+            partial_size -= 1;
+        }
+
+        let len = self.len();
+        unsafe {
+            let mut dest = self.as_mut_ptr().add(len);
+            for _ in 0..full_count {
+                ptr::copy_nonoverlapping(text.as_ptr(), dest, text.len());
+                dest = dest.add(text.len());
+            }
+            ptr::copy_nonoverlapping(text.as_ptr(), dest, partial_size);
+
+            let size = full_count * text.len() + partial_size;
+            self.set_len(len + size);
+            size
+        }
+    }
+
+    #[inline]
+    ///Appends `count` copies of `ch`, returning number of characters written before running out of space.
+    pub fn extend_with(&mut self, ch: char, count: usize) -> usize {
+        let count = cmp::min(count, self.remaining() / ch.len_utf8());
+        unsafe {
+            self.insert_repeat_unchecked(self.len(), ch, count);
+        }
+        count
+    }
+
+    #[inline]
+    ///Appends copy of content within specified byte range.
+    ///
+    ///Returns error on overflow, leaving buffer unchanged.
+    ///
+    ///Panics if range is out of bounds or does not lie on char boundaries.
+    pub fn extend_from_within<R: ops::RangeBounds<usize>>(&mut self, src: R) -> Result<(), StrBufError> {
+        let (start, end) = resolve_range(src, self.len());
+        assert!(self.is_char_boundary(start), "Range start is not on char boundary");
+        assert!(self.is_char_boundary(end), "Range end is not on char boundary");
+
+        let size = end - start;
+        if self.remaining() < size {
+            return Err(StrBufError::Overflow);
+        }
+
+        let len = self.len();
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            ptr::copy_nonoverlapping(ptr.add(start) as *const _, ptr.add(len), size);
+            self.set_len(len + size);
+        }
+        Ok(())
+    }
+
+    ///Appends all `parts`, separated by `sep`.
+    ///
+    ///If buffer is not empty, separator is also written between existing content and first part.
+    ///
+    ///Returns error on overflow, restoring buffer to its original content.
+    pub fn push_join<I: IntoIterator>(&mut self, parts: I, sep: &str) -> Result<(), StrBufError> where I::Item: AsRef<str> {
+        let len = self.len();
+        let mut need_sep = len > 0;
+        for part in parts {
+            let part = part.as_ref();
+            let sep = if need_sep { sep } else { "" };
+            if self.remaining() < sep.len() + part.len() {
+                unsafe {
+                    self.set_len(len);
+                }
+                return Err(StrBufError::Overflow);
+            }
+
+            unsafe {
+                self.push_str_unchecked(sep);
+                self.push_str_unchecked(part);
+            }
+            need_sep = true;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    ///Appends given character, returning error on overflow.
+    ///
+    ///Buffer is left unchanged on overflow.
+    pub fn push(&mut self, ch: char) -> Result<(), StrBufError> {
+        let (bytes, ch_len) = encode_utf8(ch);
+        if self.remaining() < ch_len {
+            return Err(StrBufError::Overflow);
+        }
+
+        let len = self.len();
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), self.as_mut_ptr().add(len), ch_len);
+            self.set_len(len.saturating_add(ch_len));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    ///Appends character, mirroring `String::push`.
+    ///
+    ///Panics on overflow, use `push` to handle it.
+    pub fn push_char(&mut self, ch: char) {
+        if self.push(ch).is_err() {
+            panic!("Buffer overflow");
+        }
+    }
+
+    #[inline]
+    ///Replaces bytes in range `start..end` with `bytes` without any checks, shifting rest of the content accordingly.
+    unsafe fn replace_bytes_unchecked(&mut self, start: usize, end: usize, bytes: &[u8]) {
+        let len = self.len();
+        //To make sure Miri doesn't complain, you have to derive both pointers from the same one otherwise Miri will 're-borrow' for no reason
+        let dest = self.as_mut_ptr().add(start);
+        let src = dest.add(end - start) as *const _;
+        ptr::copy(src, dest.add(bytes.len()), len - end);
+        ptr::copy_nonoverlapping(bytes.as_ptr(), dest, bytes.len());
+        self.set_len(len - (end - start) + bytes.len());
+    }
+
+    #[inline]
+    ///Inserts string at byte position `idx`, shifting rest of the content to the right.
+    ///
+    ///Returns error on overflow, leaving buffer unchanged.
+    ///
+    ///Panics if `idx` is out of bounds or does not lie on char boundary.
+    pub fn insert_str(&mut self, idx: usize, text: &str) -> Result<(), StrBufError> {
+        assert!(self.is_char_boundary(idx), "Index is not on char boundary");
+
+        if self.remaining() < text.len() {
+            Err(StrBufError::Overflow)
+        } else {
+            unsafe {
+                self.replace_bytes_unchecked(idx, idx, text.as_bytes());
+            }
+            Ok(())
+        }
+    }
+
+    #[inline]
+    ///Inserts character at byte position `idx`, shifting rest of the content to the right.
+    ///
+    ///Returns error on overflow, leaving buffer unchanged.
+    ///
+    ///Panics if `idx` is out of bounds or does not lie on char boundary.
+    pub fn insert(&mut self, idx: usize, ch: char) -> Result<(), StrBufError> {
+        assert!(self.is_char_boundary(idx), "Index is not on char boundary");
+
+        let (bytes, ch_len) = encode_utf8(ch);
+        if self.remaining() < ch_len {
+            Err(StrBufError::Overflow)
+        } else {
+            unsafe {
+                self.replace_bytes_unchecked(idx, idx, &bytes[..ch_len]);
+            }
+            Ok(())
+        }
+    }
+
+    #[inline]
+    ///Removes character at byte position `idx` and returns it, shifting rest of the content to the left.
+    ///
+    ///Panics if `idx` is out of bounds or does not lie on char boundary.
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self[idx..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("Cannot remove char at the end of buffer"),
+        };
+
+        unsafe {
+            self.replace_bytes_unchecked(idx, idx + ch.len_utf8(), &[]);
+        }
+        ch
+    }
+
+    #[inline]
+    ///Replaces specified byte range with provided string, shifting rest of the content accordingly.
+    ///
+    ///Returns error on overflow, leaving buffer unchanged.
+    ///
+    ///Panics if range is out of bounds or does not lie on char boundaries.
+    pub fn replace_range<R: ops::RangeBounds<usize>>(&mut self, range: R, replace_with: &str) -> Result<(), StrBufError> {
+        let (start, end) = resolve_range(range, self.len());
+        assert!(self.is_char_boundary(start), "Range start is not on char boundary");
+        assert!(self.is_char_boundary(end), "Range end is not on char boundary");
+
+        if self.remaining() + (end - start) < replace_with.len() {
+            Err(StrBufError::Overflow)
+        } else {
+            unsafe {
+                self.replace_bytes_unchecked(start, end, replace_with.as_bytes());
+            }
+            Ok(())
+        }
+    }
+
+    #[inline]
+    ///Splits buffer at byte position `at`, returning everything after it as new buffer.
+    ///
+    ///Panics if `at` is out of bounds or does not lie on char boundary.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(self.is_char_boundary(at), "Index is not on char boundary");
+
+        let len = self.len();
+        let mut result = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), result.as_mut_ptr(), len - at);
+            result.set_len(len - at);
+            self.set_len(at);
+        }
+        result
+    }
+
+    #[inline]
+    ///Copies content within specified byte range into new buffer.
+    ///
+    ///Returns error if content does not fit `M` capacity.
+    ///
+    ///Panics if range is out of bounds or does not lie on char boundaries.
+    pub fn substr<const M: usize, R: ops::RangeBounds<usize>>(&self, range: R) -> Result<StrBuf<M>, StrBufError> {
+        let (start, end) = resolve_range(range, self.len());
+        StrBuf::from_str_checked(&self[start..end])
+    }
+
+    #[inline]
+    ///Copies content within specified byte range into new buffer of the same capacity.
+    ///
+    ///Panics if range is out of bounds or does not lie on char boundaries.
+    pub fn substr_same<R: ops::RangeBounds<usize>>(&self, range: R) -> Self {
+        let (start, end) = resolve_range(range, self.len());
+        Self::from_str(&self[start..end])
+    }
+
+    #[inline]
+    ///Copies content before and after byte position `at` into two new buffers, keeping original intact.
+    ///
+    ///Panics if `at` is out of bounds or does not lie on char boundary.
+    pub const fn split_to_pair(&self, at: usize) -> (Self, Self) {
+        if !self.is_boundary(at) {
+            panic!("Index is not on char boundary");
+        }
+
+        let len = self.len();
+        let mut first = Self::new();
+        let mut second = Self::new();
+        let mut idx = 0;
+        while idx < at {
+            first.inner[Self::LEN_OFFSET + idx] = self.inner[Self::LEN_OFFSET + idx];
+            idx += 1;
+        }
+        while idx < len {
+            second.inner[Self::LEN_OFFSET + idx - at] = self.inner[Self::LEN_OFFSET + idx];
+            idx += 1;
+        }
+
+        unsafe {
+            (first.const_set_len(at), second.const_set_len(len - at))
+        }
+    }
+
+    #[inline(always)]
+    ///Splits buffer at byte position `at` into two buffers of the same capacity.
+    ///
+    ///Panics if `at` is out of bounds or does not lie on char boundary, failing compilation in const context.
+    pub const fn const_split_at(self, at: usize) -> (Self, Self) {
+        self.split_to_pair(at)
+    }
+
+    ///Returns iterator over consecutive chunks of content, each at most `max_bytes` long.
+    ///
+    ///Chunk never splits character and, when possible, ends right after the last whitespace within `max_bytes`.
+    ///If there is no whitespace, chunk is broken at the last character that fits.
+    ///
+    ///Character that is longer than `max_bytes` is returned as its own chunk.
+    pub fn wrap_chunks(&self, max_bytes: usize) -> impl Iterator<Item = &str> {
+        let mut rest = self.as_str();
+        core::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+
+            let mut end = rest.len();
+            if end > max_bytes {
+                end = max_bytes;
+                while !rest.is_char_boundary(end) {
+                    end -= 1;
+                }
+
+                if let Some((idx, ch)) = rest[..end].char_indices().rev().find(|(_, ch)| ch.is_whitespace()) {
+                    end = idx + ch.len_utf8();
+                }
+
+                if end == 0 {
+                    end = match rest.chars().next() {
+                        Some(ch) => ch.len_utf8(),
+                        None => rest.len(),
+                    };
+                }
+            }
+
+            let (chunk, tail) = rest.split_at(end);
+            rest = tail;
+            Some(chunk)
+        })
+    }
+
+    #[inline]
+    ///Splits content by `sep` in the same way as `str::split`, copying each part into fixed array of buffers.
+    ///
+    ///Returns array alongside with number of filled buffers.
+    ///
+    ///Returns `StrBufError::Overflow` if any part does not fit `M` capacity, and `StrBufError::TooManyParts` if there are more than `K` parts.
+    pub fn split_collect<const M: usize, const K: usize>(&self, sep: char) -> Result<([StrBuf<M>; K], usize), StrBufError> {
+        collect_parts(self.split(sep))
+    }
+
+    #[inline]
+    ///Splits content by `sep` in the same way as `str::split`, copying each part into fixed array of buffers.
+    ///
+    ///Returns array alongside with number of filled buffers.
+    ///
+    ///Returns `StrBufError::Overflow` if any part does not fit `M` capacity, and `StrBufError::TooManyParts` if there are more than `K` parts.
+    pub fn split_collect_str<const M: usize, const K: usize>(&self, sep: &str) -> Result<([StrBuf<M>; K], usize), StrBufError> {
+        collect_parts(self.split(sep))
+    }
+
+    ///Retains only characters for which `f` returns `true`, compacting buffer in place.
+    ///
+    ///If `f` panics, buffer keeps only characters that were retained before the panic.
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        struct SetLenOnDrop<'a, const N: usize> {
+            buf: &'a mut StrBuf<N>,
+            len: usize,
+        }
+
+        impl<'a, const N: usize> Drop for SetLenOnDrop<'a, N> {
+            #[inline(always)]
+            fn drop(&mut self) {
+                unsafe {
+                    self.buf.set_len(self.len);
+                }
+            }
+        }
+
+        let len = self.len();
+        let mut cursor = 0;
+        let mut guard = SetLenOnDrop {
+            buf: self,
+            len: 0,
+        };
+        let ptr = guard.buf.as_mut_ptr();
+
+        while cursor < len {
+            let ch = unsafe {
+                let text = core::str::from_utf8_unchecked(slice::from_raw_parts(ptr.add(cursor) as *const _, len - cursor));
+                match text.chars().next() {
+                    Some(ch) => ch,
+                    None => break,
+                }
+            };
+            let ch_len = ch.len_utf8();
+
+            if f(ch) {
+                if guard.len != cursor {
+                    unsafe {
+                        ptr::copy(ptr.add(cursor) as *const _, ptr.add(guard.len), ch_len);
+                    }
+                }
+                guard.len += ch_len;
+            }
+            cursor += ch_len;
+        }
+    }
+
+    #[inline]
+    ///Inserts `count` copies of `ch` at `idx` without any checks, shifting rest of the content to the right.
+    unsafe fn insert_repeat_unchecked(&mut self, idx: usize, ch: char, count: usize) {
+        let (bytes, ch_len) = encode_utf8(ch);
+        let size = ch_len * count;
+        let len = self.len();
+        let dest = self.as_mut_ptr().add(idx);
+        ptr::copy(dest as *const _, dest.add(size), len - idx);
+        if ch_len == 1 {
+            ptr::write_bytes(dest, bytes[0], size);
+        } else {
+            let mut cursor = 0;
+            while cursor < size {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), dest.add(cursor), ch_len);
+                cursor += ch_len;
+            }
+        }
+        self.set_len(len + size);
+    }
+
+    #[inline]
+    ///Pads content with `left` copies of `fill` on the left and `right` copies on the right.
+    fn pad_with(&mut self, left: usize, right: usize, fill: char) -> Result<(), StrBufError> {
+        match left.checked_add(right).and_then(|count| count.checked_mul(fill.len_utf8())) {
+            Some(size) if size <= self.remaining() => unsafe {
+                self.insert_repeat_unchecked(0, fill, left);
+                self.insert_repeat_unchecked(self.len(), fill, right);
+                Ok(())
+            },
+            _ => Err(StrBufError::Overflow),
+        }
+    }
+
+    #[inline]
+    ///Pads content on the left with `fill` until it is `width` characters long.
+    ///
+    ///Does nothing if content is already `width` characters or longer.
+    ///
+    ///Returns error if padding does not fit, leaving buffer unchanged.
+    pub fn pad_left(&mut self, width: usize, fill: char) -> Result<(), StrBufError> {
+        let count = width.saturating_sub(self.chars().count());
+        self.pad_with(count, 0, fill)
+    }
+
+    #[inline]
+    ///Pads content on the right with `fill` until it is `width` characters long.
+    ///
+    ///Does nothing if content is already `width` characters or longer.
+    ///
+    ///Returns error if padding does not fit, leaving buffer unchanged.
+    pub fn pad_right(&mut self, width: usize, fill: char) -> Result<(), StrBufError> {
+        let count = width.saturating_sub(self.chars().count());
+        self.pad_with(0, count, fill)
+    }
+
+    #[inline]
+    ///Pads content on both sides with `fill` until it is `width` characters long.
+    ///
+    ///If padding cannot be split evenly, extra character goes to the right, same as `fmt` center alignment.
+    ///
+    ///Does nothing if content is already `width` characters or longer.
+    ///
+    ///Returns error if padding does not fit, leaving buffer unchanged.
+    pub fn center(&mut self, width: usize, fill: char) -> Result<(), StrBufError> {
+        let count = width.saturating_sub(self.chars().count());
+        self.pad_with(count / 2, count - count / 2, fill)
+    }
+
+    #[inline]
+    ///Appends given string, assuming it fits.
+    ///
+    ///On overflow panics with index out of bounds.
+    pub const fn and(self, text: &str) -> Self {
+        unsafe {
+            self.and_unsafe(text.as_bytes())
+        }
+    }
+
+    #[inline(always)]
+    ///Copies content into buffer of capacity `M`.
+    ///
+    ///Panics on overflow as `from_str`.
+    pub const fn resize<const M: usize>(&self) -> StrBuf<M> {
+        StrBuf::<M>::from_str(self.as_str())
+    }
+
+    #[inline(always)]
+    ///Copies content into buffer of capacity `M`, returning error on overflow.
+    pub const fn try_resize<const M: usize>(&self) -> Result<StrBuf<M>, StrBufError> {
+        StrBuf::<M>::from_str_checked(self.as_str())
+    }
+
+    #[inline]
+    ///Creates new instance by concatenating content of `a` and `b`.
+    ///
+    ///Panics if combined content does not fit capacity, failing compilation in const context.
+    pub const fn concat<const A: usize, const B: usize>(a: &StrBuf<A>, b: &StrBuf<B>) -> Self {
+        if a.len() + b.len() > Self::CAPACITY {
+            panic!("Concatenation cannot fit static storage");
+        }
+        Self::new().and_buf(a).and_buf(b)
+    }
+
+    #[inline]
+    ///Appends content of `other` buffer, assuming it fits.
+    ///
+    ///On overflow panics with index out of bounds as `and`.
+    pub const fn and_buf<const M: usize>(self, other: &StrBuf<M>) -> Self {
+        self.and(other.as_str())
+    }
+
+    #[inline]
+    ///Appends content of `other` buffer, returning error on overflow.
+    pub const fn and_buf_checked<const M: usize>(self, other: &StrBuf<M>) -> Result<Self, StrBufError> {
+        self.and_checked(other.as_str())
+    }
+
+    #[inline]
+    ///Appends given character, assuming it fits.
+    ///
+    ///On overflow panics with index out of bounds as `and`.
+    pub const fn and_char(self, ch: char) -> Self {
+        let (bytes, len) = encode_utf8(ch);
+        unsafe {
+            self.and_unsafe(slice::from_raw_parts(bytes.as_ptr(), len))
+        }
+    }
+
+    #[inline]
+    ///Appends decimal representation of `value`, assuming it fits.
+    ///
+    ///On overflow panics with index out of bounds as `and`.
+    pub const fn and_dec(self, mut value: u64) -> Self {
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+
+        unsafe {
+            self.and_unsafe(slice::from_raw_parts(digits.as_ptr().add(start), digits.len() - start))
+        }
+    }
+
+    #[inline]
+    ///Appends decimal representation of signed `value`, assuming it fits.
+    ///
+    ///On overflow panics with index out of bounds as `and`.
+    pub const fn and_dec_signed(self, value: i64) -> Self {
+        if value < 0 {
+            self.and("-").and_dec(value.unsigned_abs())
+        } else {
+            self.and_dec(value as u64)
+        }
+    }
+
+    #[inline]
+    ///Appends hexadecimal representation of `value`, without prefix and leading zeros, assuming it fits.
+    ///
+    ///On overflow panics with index out of bounds as `and`.
+    pub const fn and_hex(self, mut value: u64, uppercase: bool) -> Self {
+        let alphabet = if uppercase {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let mut digits = [0u8; 16];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = alphabet[(value & 0xF) as usize];
+            value >>= 4;
+            if value == 0 {
+                break;
+            }
+        }
+
+        unsafe {
+            self.and_unsafe(slice::from_raw_parts(digits.as_ptr().add(start), digits.len() - start))
+        }
+    }
+
+    #[inline]
+    ///Appends given string, returning error on overflow.
+    pub const fn and_checked(self, text: &str) -> Result<Self, StrBufError> {
+        if self.remaining() >= text.len() {
+            Ok(self.and(text))
+        } else {
+            Err(StrBufError::Overflow)
+        }
+    }
+
+    #[inline]
+    ///Appends given string, truncating on overflow to the last char boundary that fits.
+    pub const fn and_lossy(self, text: &str) -> Self {
+        let bytes = text.as_bytes();
+        let size = floor_char_boundary_of(bytes, self.remaining());
+
+        unsafe {
+            self.and_unsafe(slice::from_raw_parts(bytes.as_ptr(), size))
+        }
+    }
+
+   #[inline]
+    ///Unsafely appends given bytes, assuming valid utf-8.
+    ///
+    ///On overflow panics with index out of bounds as `and`.
+    pub const unsafe fn and_unsafe(mut self, bytes: &[u8]) -> Self {
+        debug_assert!(self.remaining() >= bytes.len(), "Buffer overflow");
+
+        let mut idx = 0;
+        let cursor = self.len();
+        while idx < bytes.len() {
+            self.inner[Self::LEN_OFFSET + cursor + idx] = mem::MaybeUninit::new(bytes[idx]);
+            idx += 1;
+        }
+        self.const_set_len(cursor + bytes.len())
+    }
+
+    #[inline(always)]
+    ///Access str from underlying storage
+    ///
+    ///Returns empty if nothing has been written into buffer yet.
+    pub const fn as_str(&self) -> &str {
+        unsafe {
+            core::str::from_utf8_unchecked(self.as_slice())
+        }
+    }
+
+    #[inline]
+    ///Compares content with `other` in const context.
+    pub const fn const_eq(&self, other: &str) -> bool {
+        self.len() == other.len() && bytes_eq_at(self.as_slice(), 0, other.as_bytes())
+    }
+
+    #[inline]
+    ///Compares content with `other` buffer of any capacity in const context.
+    pub const fn const_eq_buf<const M: usize>(&self, other: &StrBuf<M>) -> bool {
+        self.const_eq(other.as_str())
+    }
+
+    #[inline]
+    ///Returns whether content starts with `prefix` in const context.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        bytes_eq_at(self.as_slice(), 0, prefix.as_bytes())
+    }
+
+    #[inline]
+    ///Returns whether content ends with `suffix` in const context.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        let len = self.len();
+        len >= suffix.len() && bytes_eq_at(self.as_slice(), len - suffix.len(), suffix.as_bytes())
+    }
+
+    #[inline]
+    ///Returns byte index of the first occurrence of `needle` in const context.
+    ///
+    ///Empty `needle` is found at `0`.
+    pub const fn const_find(&self, needle: &str) -> Option<usize> {
+        let bytes = self.as_slice();
+        let needle = needle.as_bytes();
+        if needle.len() > bytes.len() {
+            return None;
+        }
+
+        let mut idx = 0;
+        while idx <= bytes.len() - needle.len() {
+            if bytes_eq_at(bytes, idx, needle) {
+                return Some(idx);
+            }
+            idx += 1;
+        }
+        None
+    }
+
+    #[inline]
+    ///Returns whether content contains `needle` in const context.
+    pub const fn const_contains(&self, needle: &str) -> bool {
+        self.const_find(needle).is_some()
+    }
+
+    #[inline]
+    ///Returns whether content consists only of ASCII characters.
+    pub const fn is_ascii(&self) -> bool {
+        is_ascii_bytes(self.as_slice())
+    }
+
+    #[inline(always)]
+    ///Modifies this string to convert all its characters into ASCII lower case equivalent
+    pub const fn into_ascii_lowercase(mut self) -> Self {
         let len = Self::LEN_OFFSET + self.len();
         let mut idx = Self::LEN_OFFSET;
         loop {
@@ -462,80 +2426,653 @@ impl<const N: usize> StrBuf<N> {
         }
     }
 
-    ///Trims of whitespaces on the right in place.
-    pub fn make_trim(&mut self) {
-        let this = self.as_str();
-        let len = this.len();
-        let mut trim_left_count = 0usize;
-        let mut trim_right_count = 0usize;
-        let mut chars = this.chars();
-        while let Some(ch) = chars.next() {
-            if ch.is_whitespace() {
-                trim_left_count = trim_left_count.saturating_add(ch.len_utf8());
-            } else {
-                break;
+    #[inline(always)]
+    ///Modifies this string to convert first character into ASCII upper case and the rest into ASCII lower case
+    pub const fn into_capitalized(mut self) -> Self {
+        let len = Self::LEN_OFFSET + self.len();
+        let mut idx = Self::LEN_OFFSET;
+        loop {
+            if idx >= len {
+                break;
+            }
+
+            self.inner[idx] = unsafe {
+                let byte = self.inner[idx].assume_init();
+                if idx == Self::LEN_OFFSET {
+                    mem::MaybeUninit::new(byte.to_ascii_uppercase())
+                } else {
+                    mem::MaybeUninit::new(byte.to_ascii_lowercase())
+                }
+            };
+            idx = idx.saturating_add(1);
+        }
+        self
+    }
+
+    #[inline(always)]
+    ///Converts first character of this string to ASCII upper case and the rest to ASCII lower case in-place.
+    ///
+    ///Non-ASCII characters are left unchanged.
+    pub fn make_capitalize(&mut self) {
+        unsafe {
+            if let Some((first, rest)) = self.as_mut_slice().split_first_mut() {
+                first.make_ascii_uppercase();
+                rest.make_ascii_lowercase();
+            }
+        }
+    }
+
+    ///Converts ASCII content to snake case in place, e.g. `HTTPServer` becomes `http_server`.
+    ///
+    ///Underscore is inserted before each upper case word and all ASCII letters are converted to lower case.
+    ///Non-ASCII characters are left unchanged.
+    ///
+    ///Returns error if result would not fit capacity, leaving buffer unchanged.
+    pub fn make_snake_case(&mut self) -> Result<(), StrBufError> {
+        let len = self.len();
+        let bytes = self.as_slice();
+        let mut count = 0;
+        let mut idx = 1;
+        while idx < len {
+            let next = if idx + 1 < len { bytes[idx + 1] } else { 0 };
+            if is_ascii_word_boundary(bytes[idx - 1], bytes[idx], next) {
+                count += 1;
+            }
+            idx += 1;
+        }
+
+        if self.remaining() < count {
+            return Err(StrBufError::Overflow);
+        }
+
+        //Go backwards so that content can be shifted to the right without overwriting bytes yet to be read.
+        let ptr = self.as_mut_ptr();
+        let mut read = len;
+        let mut write = len + count;
+        let mut next = 0;
+        unsafe {
+            while read > 0 {
+                read -= 1;
+                let cur = *ptr.add(read);
+                write -= 1;
+                *ptr.add(write) = cur.to_ascii_lowercase();
+                if read > 0 && is_ascii_word_boundary(*ptr.add(read - 1), cur, next) {
+                    write -= 1;
+                    *ptr.add(write) = b'_';
+                }
+                next = cur;
+            }
+
+            self.set_len(len + count);
+        }
+        Ok(())
+    }
+
+    ///Converts ASCII content to camel case into new buffer, e.g. `http_server` and `HTTPServer` become `httpServer`.
+    ///
+    ///Words are separated by underscores or by case change as in `make_snake_case`.
+    ///First word is converted to lower case while each subsequent word is capitalized.
+    ///Non-ASCII characters are left unchanged.
+    ///
+    ///Returns error if result does not fit `M` capacity.
+    pub fn to_camel_case<const M: usize>(&self) -> Result<StrBuf<M>, StrBufError> {
+        let bytes = self.as_slice();
+        let mut result = StrBuf::<M>::new();
+        let mut is_word_start = false;
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let cur = bytes[idx];
+            idx += 1;
+
+            if cur == b'_' {
+                is_word_start = true;
+                continue;
+            }
+
+            let prev = if idx > 1 { bytes[idx - 2] } else { 0 };
+            let next = if idx < bytes.len() { bytes[idx] } else { 0 };
+            if is_ascii_word_boundary(prev, cur, next) {
+                is_word_start = true;
+            }
+
+            let byte = if is_word_start && result.len() > 0 {
+                cur.to_ascii_uppercase()
+            } else {
+                cur.to_ascii_lowercase()
+            };
+            is_word_start = false;
+
+            if result.remaining() == 0 {
+                return Err(StrBufError::Overflow);
+            }
+            //Result consists of whole characters as only ASCII bytes are modified or removed.
+            unsafe {
+                let len = result.len();
+                *result.as_mut_ptr().add(len) = byte;
+                result.set_len(len + 1);
+            }
+        }
+
+        Ok(result)
+    }
+
+    ///Drops first `start` bytes, shifting rest of content to the front, in const context.
+    const fn into_shifted_front(mut self, start: usize) -> Self {
+        if start == 0 {
+            return self;
+        }
+
+        let len = self.len();
+        let mut idx = 0;
+        while idx < len - start {
+            self.inner[Self::LEN_OFFSET + idx] = self.inner[Self::LEN_OFFSET + start + idx];
+            idx += 1;
+        }
+        unsafe {
+            self.const_set_len(len - start)
+        }
+    }
+
+    ///Returns copy with whitespaces trimmed on both sides, usable in const context.
+    pub const fn into_trimmed(self) -> Self {
+        self.into_trim_right().into_trim_left()
+    }
+
+    ///Returns copy with whitespaces trimmed on the left, usable in const context.
+    pub const fn into_trim_left(self) -> Self {
+        let len = self.len();
+        let mut start = 0;
+        {
+            let bytes = self.as_slice();
+            while start < len {
+                let (code, ch_len) = decode_utf8(bytes, start);
+                if !is_whitespace_code(code) {
+                    break;
+                }
+                start += ch_len;
+            }
+        }
+
+        self.into_shifted_front(start)
+    }
+
+    ///Returns copy with whitespaces trimmed on the right, usable in const context.
+    pub const fn into_trim_right(self) -> Self {
+        let mut end = self.len();
+        {
+            let bytes = self.as_slice();
+            while end > 0 {
+                let start = prev_char_start(bytes, end);
+                let (code, _) = decode_utf8(bytes, start);
+                if !is_whitespace_code(code) {
+                    break;
+                }
+                end = start;
+            }
+        }
+
+        unsafe {
+            self.const_set_len(end)
+        }
+    }
+
+    ///Trims of whitespaces on the right in place.
+    pub fn make_trim(&mut self) {
+        let this = self.as_str();
+        let len = this.len();
+        let mut trim_left_count = 0usize;
+        let mut trim_right_count = 0usize;
+        let mut chars = this.chars();
+        while let Some(ch) = chars.next() {
+            if ch.is_whitespace() {
+                trim_left_count = trim_left_count.saturating_add(ch.len_utf8());
+            } else {
+                break;
+            }
+        }
+
+        for ch in chars.rev() {
+            if ch.is_whitespace() {
+                trim_right_count = trim_right_count.saturating_add(ch.len_utf8());
+            } else {
+                break;
+            }
+        }
+
+        let new_len = len.saturating_sub(trim_left_count).saturating_sub(trim_right_count);
+        if new_len != len {
+            unsafe {
+                //To make sure Miri doesn't complain, you have to derive both pointers from the same one otherwise Miri will 're-borrow' for no reason
+                let dest = self.as_mut_ptr();
+                let src = dest.add(trim_left_count) as *const _;
+                ptr::copy(src, dest, new_len);
+                self.set_len(new_len);
+            }
+        }
+    }
+
+    #[inline]
+    ///Trims of whitespaces on the right in place.
+    pub fn make_trim_left(&mut self) {
+        let this = self.as_str();
+        let len = this.len();
+        let mut trim_count = 0usize;
+        for ch in this.chars() {
+            if ch.is_whitespace() {
+                trim_count = trim_count.saturating_add(ch.len_utf8());
+            } else {
+                break;
+            }
+        }
+
+        let new_len = len.saturating_sub(trim_count);
+        unsafe {
+            let dest = self.as_mut_ptr();
+            let src = dest.add(trim_count);
+            ptr::copy(src, dest, new_len);
+            self.set_len(new_len);
+        }
+    }
+
+    #[inline]
+    ///Trims of whitespaces on the right in place.
+    pub fn make_trim_right(&mut self) {
+        let this = self.as_str();
+        let len = this.len();
+        let mut trim_count = 0usize;
+        for ch in this.chars().rev() {
+            if ch.is_whitespace() {
+                trim_count = trim_count.saturating_add(ch.len_utf8());
+            } else {
+                break;
+            }
+        }
+
+        unsafe {
+            self.set_len(len.saturating_sub(trim_count))
+        }
+    }
+
+    ///Collapses every run of consecutive whitespace characters into single ASCII space in place.
+    ///
+    ///Buffer that consists only of whitespaces becomes single space, use `make_trim` to get rid of it.
+    pub fn dedup_whitespace(&mut self) {
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        let mut read = 0;
+        let mut write = 0;
+        let mut is_prev_whitespace = false;
+
+        while read < len {
+            let ch = unsafe {
+                let text = core::str::from_utf8_unchecked(slice::from_raw_parts(ptr.add(read) as *const _, len - read));
+                match text.chars().next() {
+                    Some(ch) => ch,
+                    None => break,
+                }
+            };
+            let ch_len = ch.len_utf8();
+
+            if ch.is_whitespace() {
+                if !is_prev_whitespace {
+                    unsafe {
+                        *ptr.add(write) = b' ';
+                    }
+                    write += 1;
+                }
+                is_prev_whitespace = true;
+            } else {
+                if write != read {
+                    unsafe {
+                        ptr::copy(ptr.add(read) as *const _, ptr.add(write), ch_len);
+                    }
+                }
+                write += ch_len;
+                is_prev_whitespace = false;
+            }
+            read += ch_len;
+        }
+
+        unsafe {
+            self.set_len(write);
+        }
+    }
+
+    ///Replaces each tab character with spaces up to the next tab stop in place.
+    ///
+    ///Columns are counted in characters, starting over after each new line.
+    ///When `tab_width` is zero, tabs are removed.
+    ///
+    ///Returns error if result would not fit capacity, leaving buffer unchanged.
+    pub fn expand_tabs(&mut self, tab_width: usize) -> Result<(), StrBufError> {
+        #[inline(always)]
+        fn tab_size(column: usize, tab_width: usize) -> usize {
+            match tab_width {
+                0 => 0,
+                tab_width => tab_width - column % tab_width,
+            }
+        }
+
+        let len = self.len();
+        let mut new_len = 0usize;
+        let mut column = 0;
+        for byte in self.as_slice() {
+            match byte {
+                b'\t' => {
+                    let size = tab_size(column, tab_width);
+                    column = column.saturating_add(size);
+                    new_len = new_len.saturating_add(size);
+                },
+                b'\n' => {
+                    column = 0;
+                    new_len = new_len.saturating_add(1);
+                },
+                byte => {
+                    if byte & 0xC0 != 0x80 {
+                        column = column.saturating_add(1);
+                    }
+                    new_len = new_len.saturating_add(1);
+                }
+            }
+        }
+
+        if new_len > Self::capacity() {
+            return Err(StrBufError::Overflow);
+        }
+
+        //When string grows, content is moved towards the end first, so that writing cursor never overtakes reading cursor.
+        let shift = new_len.saturating_sub(len);
+        let ptr = self.as_mut_ptr();
+        let mut write = 0;
+        column = 0;
+        unsafe {
+            ptr::copy(ptr as *const _, ptr.add(shift), len);
+
+            for read in shift..shift + len {
+                let byte = *ptr.add(read);
+                match byte {
+                    b'\t' => {
+                        let size = tab_size(column, tab_width);
+                        ptr::write_bytes(ptr.add(write), b' ', size);
+                        column += size;
+                        write += size;
+                        continue;
+                    },
+                    b'\n' => column = 0,
+                    byte => if byte & 0xC0 != 0x80 {
+                        column += 1;
+                    },
+                }
+                *ptr.add(write) = byte;
+                write += 1;
+            }
+
+            self.set_len(new_len);
+        }
+        Ok(())
+    }
+
+    ///Inserts `prefix` at the beginning of every line in place.
+    ///
+    ///Empty line after trailing new line character is not indented, as well as empty buffer.
+    ///
+    ///Returns error if result would not fit capacity, leaving buffer unchanged.
+    pub fn make_indent(&mut self, prefix: &str) -> Result<(), StrBufError> {
+        let len = self.len();
+        let bytes = self.as_slice();
+        let line_count = match bytes.last() {
+            None => 0,
+            Some(b'\n') => bytes.iter().filter(|byte| **byte == b'\n').count(),
+            Some(_) => bytes.iter().filter(|byte| **byte == b'\n').count() + 1,
+        };
+
+        match line_count.checked_mul(prefix.len()) {
+            Some(size) if size <= self.remaining() => (),
+            _ => return Err(StrBufError::Overflow),
+        }
+        if line_count == 0 || prefix.is_empty() {
+            return Ok(());
+        }
+
+        //Content is moved towards the end first, so that writing cursor never overtakes reading cursor.
+        let shift = line_count * prefix.len();
+        let ptr = self.as_mut_ptr();
+        let mut write = 0;
+        let mut is_line_start = true;
+        unsafe {
+            ptr::copy(ptr as *const _, ptr.add(shift), len);
+
+            for read in shift..shift + len {
+                if is_line_start {
+                    ptr::copy_nonoverlapping(prefix.as_ptr(), ptr.add(write), prefix.len());
+                    write += prefix.len();
+                }
+
+                let byte = *ptr.add(read);
+                *ptr.add(write) = byte;
+                write += 1;
+                is_line_start = byte == b'\n';
+            }
+
+            self.set_len(len + shift);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    ///Trims characters matching `pat` on the left in place.
+    fn make_trim_start_by<F: FnMut(char) -> bool>(&mut self, mut pat: F) {
+        let trim_count = self.len() - self.trim_start_matches(|ch| pat(ch)).len();
+        if trim_count > 0 {
+            unsafe {
+                self.replace_bytes_unchecked(0, trim_count, &[]);
+            }
+        }
+    }
+
+    #[inline]
+    ///Trims characters matching `pat` on the right in place.
+    fn make_trim_end_by<F: FnMut(char) -> bool>(&mut self, mut pat: F) {
+        let new_len = self.trim_end_matches(|ch| pat(ch)).len();
+        unsafe {
+            self.set_len(new_len);
+        }
+    }
+
+    #[inline]
+    ///Trims characters contained in `chars` on both sides in place.
+    pub fn make_trim_matches(&mut self, chars: &[char]) {
+        self.make_trim_end_by(|ch| chars.contains(&ch));
+        self.make_trim_start_by(|ch| chars.contains(&ch));
+    }
+
+    #[inline]
+    ///Trims characters contained in `chars` on the left in place.
+    pub fn make_trim_start_matches(&mut self, chars: &[char]) {
+        self.make_trim_start_by(|ch| chars.contains(&ch));
+    }
+
+    #[inline]
+    ///Trims characters contained in `chars` on the right in place.
+    pub fn make_trim_end_matches(&mut self, chars: &[char]) {
+        self.make_trim_end_by(|ch| chars.contains(&ch));
+    }
+
+    ///Reverses order of characters in place.
+    pub fn make_reverse(&mut self) {
+        unsafe {
+            let bytes = self.as_mut_slice();
+            bytes.reverse();
+
+            //After reversal, each multi-byte character has its continuation bytes in front of leading byte
+            let mut idx = 0;
+            while idx < bytes.len() {
+                let start = idx;
+                while bytes[idx] & 0xC0 == 0x80 {
+                    idx += 1;
+                }
+                bytes[start..=idx].reverse();
+                idx += 1;
             }
         }
+    }
 
-        for ch in chars.rev() {
-            if ch.is_whitespace() {
-                trim_right_count = trim_right_count.saturating_add(ch.len_utf8());
-            } else {
-                break;
-            }
+    #[inline]
+    ///Returns copy without `prefix`, or `None` if content doesn't start with it, usable in const context.
+    pub const fn into_strip_prefix(self, prefix: &str) -> Option<Self> {
+        if self.const_starts_with(prefix) {
+            Some(self.into_shifted_front(prefix.len()))
+        } else {
+            None
         }
+    }
 
-        let new_len = len.saturating_sub(trim_left_count).saturating_sub(trim_right_count);
-        if new_len != len {
+    #[inline]
+    ///Returns copy without `suffix`, or `None` if content doesn't end with it, usable in const context.
+    pub const fn into_strip_suffix(self, suffix: &str) -> Option<Self> {
+        if self.const_ends_with(suffix) {
+            let len = self.len() - suffix.len();
             unsafe {
-                //To make sure Miri doesn't complain, you have to derive both pointers from the same one otherwise Miri will 're-borrow' for no reason
-                let dest = self.as_mut_ptr();
-                let src = dest.add(trim_left_count) as *const _;
-                ptr::copy(src, dest, new_len);
-                self.set_len(new_len);
+                Some(self.const_set_len(len))
             }
+        } else {
+            None
         }
     }
 
     #[inline]
-    ///Trims of whitespaces on the right in place.
-    pub fn make_trim_left(&mut self) {
-        let this = self.as_str();
-        let len = this.len();
-        let mut trim_count = 0usize;
-        for ch in this.chars() {
-            if ch.is_whitespace() {
-                trim_count = trim_count.saturating_add(ch.len_utf8());
-            } else {
-                break;
-            }
+    ///Removes `prefix` from the beginning in place, if present.
+    ///
+    ///Returns whether prefix has been removed.
+    pub fn make_strip_prefix(&mut self, prefix: &str) -> bool {
+        if !self.starts_with(prefix) {
+            return false;
         }
 
-        let new_len = len.saturating_sub(trim_count);
+        let new_len = self.len() - prefix.len();
         unsafe {
             let dest = self.as_mut_ptr();
-            let src = dest.add(trim_count);
+            let src = dest.add(prefix.len()) as *const _;
             ptr::copy(src, dest, new_len);
             self.set_len(new_len);
         }
+        !prefix.is_empty()
     }
 
     #[inline]
-    ///Trims of whitespaces on the right in place.
-    pub fn make_trim_right(&mut self) {
-        let this = self.as_str();
-        let len = this.len();
-        let mut trim_count = 0usize;
-        for ch in this.chars().rev() {
-            if ch.is_whitespace() {
-                trim_count = trim_count.saturating_add(ch.len_utf8());
-            } else {
-                break;
-            }
+    ///Removes `suffix` from the end in place, if present.
+    ///
+    ///Returns whether suffix has been removed.
+    pub fn make_strip_suffix(&mut self, suffix: &str) -> bool {
+        if !self.ends_with(suffix) {
+            return false;
         }
 
         unsafe {
-            self.set_len(len.saturating_sub(trim_count))
+            self.set_len(self.len() - suffix.len());
+        }
+        !suffix.is_empty()
+    }
+
+    ///Replaces every occurrence of `from` with `to` in place, returning number of replacements.
+    ///
+    ///Matches are found in the same way as `str::replace` does, including empty `from` which matches at every character boundary.
+    ///
+    ///Returns error if result would not fit capacity, leaving buffer unchanged.
+    pub fn make_replace(&mut self, from: &str, to: &str) -> Result<usize, StrBufError> {
+        let count = self.matches(from).count();
+        let len = self.len();
+        let new_len = match count.checked_mul(to.len()).and_then(|grow| (len - count * from.len()).checked_add(grow)) {
+            Some(new_len) if new_len <= Self::capacity() => new_len,
+            _ => return Err(StrBufError::Overflow),
+        };
+
+        if count == 0 || (from.is_empty() && to.is_empty()) {
+            return Ok(count);
+        }
+
+        //When string grows, content is moved towards the end first, so that writing cursor never overtakes reading cursor.
+        let shift = new_len.saturating_sub(len);
+        let ptr = self.as_mut_ptr();
+        let mut read = 0;
+        let mut write = 0;
+        unsafe {
+            ptr::copy(ptr as *const _, ptr.add(shift), len);
+
+            loop {
+                let src = ptr.add(shift + read);
+                let tail = core::str::from_utf8_unchecked(slice::from_raw_parts(src as *const _, len - read));
+                let pos = match find_str(tail, from) {
+                    Some(pos) => pos,
+                    None => break,
+                };
+                let next_ch_len = match tail[pos..].chars().next() {
+                    Some(ch) => ch.len_utf8(),
+                    None => 0,
+                };
+
+                ptr::copy(src as *const _, ptr.add(write), pos);
+                write += pos;
+                read += pos + from.len();
+                ptr::copy_nonoverlapping(to.as_ptr(), ptr.add(write), to.len());
+                write += to.len();
+
+                if from.is_empty() {
+                    //Empty pattern matches at every boundary, so skip over next character
+                    if read == len {
+                        break;
+                    }
+                    ptr::copy(ptr.add(shift + read) as *const _, ptr.add(write), next_ch_len);
+                    read += next_ch_len;
+                    write += next_ch_len;
+                }
+            }
+
+            ptr::copy(ptr.add(shift + read) as *const _, ptr.add(write), len - read);
+            self.set_len(new_len);
+        }
+
+        Ok(count)
+    }
+
+    ///Replaces every occurrence of character `from` with `to` in place, returning number of replacements.
+    ///
+    ///Returns error if result would not fit capacity, leaving buffer unchanged.
+    pub fn replace_char(&mut self, from: char, to: char) -> Result<usize, StrBufError> {
+        let (from_bytes, from_len) = encode_utf8(from);
+        let (to_bytes, to_len) = encode_utf8(to);
+        let from = unsafe {
+            core::str::from_utf8_unchecked(&from_bytes[..from_len])
+        };
+
+        if from_len == to_len {
+            let mut count = 0;
+            let mut cursor = 0;
+            while let Some(pos) = find_str(&self[cursor..], from) {
+                cursor += pos;
+                unsafe {
+                    ptr::copy_nonoverlapping(to_bytes.as_ptr(), self.as_mut_ptr().add(cursor), to_len);
+                }
+                cursor += to_len;
+                count += 1;
+            }
+            Ok(count)
+        } else {
+            let to = unsafe {
+                core::str::from_utf8_unchecked(&to_bytes[..to_len])
+            };
+            self.make_replace(from, to)
+        }
+    }
+
+    #[inline]
+    ///Removes every non-overlapping occurrence of `pat` in place.
+    ///
+    ///Empty `pat` removes nothing.
+    pub fn remove_matches(&mut self, pat: &str) {
+        if !pat.is_empty() {
+            //Removal only shrinks content, hence it cannot overflow
+            let _ = self.make_replace(pat, "");
         }
     }
 
@@ -549,6 +3086,32 @@ impl<const N: usize> StrBuf<N> {
         }
         Some(ch)
     }
+
+    #[inline]
+    ///Removes first character from the buffer, if any present
+    pub fn pop_front(&mut self) -> Option<char> {
+        let ch = self.chars().next()?;
+        unsafe {
+            self.replace_bytes_unchecked(0, ch.len_utf8(), &[]);
+        }
+        Some(ch)
+    }
+
+    #[inline]
+    ///Checks whether content is equal to `other`, ignoring case.
+    ///
+    ///Characters are compared by their lower case mapping, which may consist of multiple characters.
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        self.chars().flat_map(char::to_lowercase).eq(other.chars().flat_map(char::to_lowercase))
+    }
+
+    #[inline]
+    ///Compares content with `other`, ignoring case.
+    ///
+    ///Characters are compared by their lower case mapping, which may consist of multiple characters.
+    pub fn cmp_ignore_case(&self, other: &str) -> cmp::Ordering {
+        self.chars().flat_map(char::to_lowercase).cmp(other.chars().flat_map(char::to_lowercase))
+    }
 }
 
 impl<const S: usize> AsRef<str> for StrBuf<S> {
@@ -606,11 +3169,18 @@ impl<const S: usize> ops::Deref for StrBuf<S> {
     }
 }
 
+impl<const S: usize> ops::DerefMut for StrBuf<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
 impl<const S: usize> Eq for StrBuf<S> {}
 
-impl<const S: usize> PartialEq<StrBuf<S>> for StrBuf<S> {
+impl<const A: usize, const B: usize> PartialEq<StrBuf<B>> for StrBuf<A> {
     #[inline(always)]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &StrBuf<B>) -> bool {
         self.as_str() == other.as_str()
     }
 }
@@ -643,15 +3213,86 @@ impl<const S: usize> PartialEq<&str> for StrBuf<S> {
     }
 }
 
+impl<const S: usize> PartialEq<[u8]> for StrBuf<S> {
+    #[inline(always)]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<const S: usize> PartialEq<&[u8]> for StrBuf<S> {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<const S: usize, const M: usize> PartialEq<[u8; M]> for StrBuf<S> {
+    #[inline(always)]
+    fn eq(&self, other: &[u8; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<const S: usize> PartialEq<StrBuf<S>> for [u8] {
+    #[inline(always)]
+    fn eq(&self, other: &StrBuf<S>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<const S: usize> PartialEq<StrBuf<S>> for &[u8] {
+    #[inline(always)]
+    fn eq(&self, other: &StrBuf<S>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<const S: usize, const M: usize> PartialEq<StrBuf<S>> for [u8; M] {
+    #[inline(always)]
+    fn eq(&self, other: &StrBuf<S>) -> bool {
+        self == other.as_slice()
+    }
+}
+
 impl<const S: usize> cmp::Ord for StrBuf<S> {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         self.as_str().cmp(other.as_str())
     }
 }
 
-impl<const S: usize> PartialOrd for StrBuf<S> {
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        Some(self.cmp(other))
+impl<const A: usize, const B: usize> PartialOrd<StrBuf<B>> for StrBuf<A> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &StrBuf<B>) -> Option<cmp::Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+
+impl<const S: usize> PartialOrd<str> for StrBuf<S> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &str) -> Option<cmp::Ordering> {
+        Some(self.as_str().cmp(other))
+    }
+}
+
+impl<const S: usize> PartialOrd<&str> for StrBuf<S> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&str) -> Option<cmp::Ordering> {
+        Some(self.as_str().cmp(*other))
+    }
+}
+
+impl<const S: usize> PartialOrd<StrBuf<S>> for str {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &StrBuf<S>) -> Option<cmp::Ordering> {
+        Some(self.cmp(other.as_str()))
+    }
+}
+
+impl<const S: usize> PartialOrd<StrBuf<S>> for &str {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &StrBuf<S>) -> Option<cmp::Ordering> {
+        Some((*self).cmp(other.as_str()))
     }
 }
 
@@ -670,6 +3311,200 @@ impl<const S: usize> core::convert::TryFrom<&str> for StrBuf<S> {
     }
 }
 
+impl<const S: usize> core::convert::TryFrom<&[u8]> for StrBuf<S> {
+    type Error = StrBufError;
+
+    #[inline(always)]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_utf8(bytes)
+    }
+}
+
+impl<const S: usize> core::convert::TryFrom<char> for StrBuf<S> {
+    type Error = StrBufError;
+
+    #[inline]
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        if ch.len_utf8() <= Self::capacity() {
+            Ok(Self::from_char(ch))
+        } else {
+            Err(StrBufError::Overflow)
+        }
+    }
+}
+
+impl<const S: usize> core::convert::TryFrom<&[char]> for StrBuf<S> {
+    type Error = StrBufError;
+
+    #[inline(always)]
+    fn try_from(chars: &[char]) -> Result<Self, Self::Error> {
+        Self::from_chars(chars)
+    }
+}
+
+impl<const S: usize> core::convert::TryFrom<fmt::Arguments<'_>> for StrBuf<S> {
+    type Error = StrBufError;
+
+    #[inline(always)]
+    fn try_from(args: fmt::Arguments<'_>) -> Result<Self, Self::Error> {
+        Self::format(args)
+    }
+}
+
+impl<const A: usize, const B: usize> core::convert::TryFrom<&StrBuf<A>> for StrBuf<B> {
+    type Error = StrBufError;
+
+    #[inline(always)]
+    fn try_from(other: &StrBuf<A>) -> Result<Self, Self::Error> {
+        other.try_resize()
+    }
+}
+
+///Collects characters, stopping at the first one that doesn't fit.
+impl<const S: usize> core::iter::FromIterator<char> for StrBuf<S> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+///Collects string slices, truncating as `push_str` and stopping on overflow.
+impl<'a, const S: usize> core::iter::FromIterator<&'a str> for StrBuf<S> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+///Collects buffers, truncating as `push_str` and stopping on overflow.
+impl<const S: usize, const M: usize> core::iter::FromIterator<StrBuf<M>> for StrBuf<S> {
+    fn from_iter<I: IntoIterator<Item = StrBuf<M>>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+///Appends characters, stopping at the first one that doesn't fit.
+///
+///Iterator is not consumed any further once buffer is full.
+impl<const S: usize> Extend<char> for StrBuf<S> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        if self.is_full() {
+            return;
+        }
+        for ch in iter {
+            if self.push(ch).is_err() || self.is_full() {
+                break;
+            }
+        }
+    }
+}
+
+///Appends string slices, truncating as `push_str` and stopping on overflow.
+///
+///Iterator is not consumed any further once buffer is full.
+impl<'a, const S: usize> Extend<&'a str> for StrBuf<S> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        if self.is_full() {
+            return;
+        }
+        for text in iter {
+            if self.push_str(text) < text.len() || self.is_full() {
+                break;
+            }
+        }
+    }
+}
+
+///Appends buffers, truncating as `push_str` and stopping on overflow.
+///
+///Iterator is not consumed any further once buffer is full.
+impl<const S: usize, const M: usize> Extend<StrBuf<M>> for StrBuf<S> {
+    fn extend<I: IntoIterator<Item = StrBuf<M>>>(&mut self, iter: I) {
+        if self.is_full() {
+            return;
+        }
+        for text in iter {
+            if self.push_str(text.as_str()) < text.len() || self.is_full() {
+                break;
+            }
+        }
+    }
+}
+
+impl<const S: usize> IntoIterator for StrBuf<S> {
+    type Item = char;
+    type IntoIter = IntoChars<S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoChars::new(self)
+    }
+}
+
+impl<'a, const S: usize> IntoIterator for &'a StrBuf<S> {
+    type Item = char;
+    type IntoIter = core::str::Chars<'a>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
+    }
+}
+
+///Appends string, panicking on overflow as `and`.
+///
+///Use `push_str` or `try_push_str` to handle overflow.
+impl<const S: usize> ops::Add<&str> for StrBuf<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, text: &str) -> Self::Output {
+        self += text;
+        self
+    }
+}
+
+///Appends character, panicking on overflow as `and`.
+///
+///Use `push` to handle overflow.
+impl<const S: usize> ops::Add<char> for StrBuf<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(mut self, ch: char) -> Self::Output {
+        self += ch;
+        self
+    }
+}
+
+///Appends string, panicking on overflow as `and`.
+///
+///Use `push_str` or `try_push_str` to handle overflow.
+impl<const S: usize> ops::AddAssign<&str> for StrBuf<S> {
+    #[inline]
+    fn add_assign(&mut self, text: &str) {
+        if self.try_push_str(text).is_err() {
+            panic!("Buffer overflow");
+        }
+    }
+}
+
+///Appends character, panicking on overflow as `and`.
+///
+///Use `push` to handle overflow.
+impl<const S: usize> ops::AddAssign<char> for StrBuf<S> {
+    #[inline]
+    fn add_assign(&mut self, ch: char) {
+        if self.push(ch).is_err() {
+            panic!("Buffer overflow");
+        }
+    }
+}
+
 impl<const S: usize> core::str::FromStr for StrBuf<S> {
     type Err = StrBufError;
 
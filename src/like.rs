@@ -0,0 +1,64 @@
+use core::fmt;
+
+use crate::StrBuf;
+
+///Object safe interface over `StrBuf` of any capacity.
+///
+///Allows to accept `&mut dyn StrBufLike` instead of being generic over capacity.
+pub trait StrBufLike {
+    ///Access written content.
+    fn as_str(&self) -> &str;
+    ///Appends given string, truncating on overflow, returning number of written bytes.
+    fn push_str(&mut self, text: &str) -> usize;
+    ///Returns number of bytes left (not written yet).
+    fn remaining(&self) -> usize;
+    ///Returns buffer overall capacity.
+    fn capacity(&self) -> usize;
+    ///Clears the content of buffer.
+    fn clear(&mut self);
+    ///Shortens the buffer, keeping at most first `new_len` bytes, rounding down to char boundary.
+    fn truncate(&mut self, new_len: usize);
+}
+
+impl<const N: usize> StrBufLike for StrBuf<N> {
+    #[inline(always)]
+    fn as_str(&self) -> &str {
+        StrBuf::as_str(self)
+    }
+
+    #[inline(always)]
+    fn push_str(&mut self, text: &str) -> usize {
+        StrBuf::push_str(self, text)
+    }
+
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        StrBuf::remaining(self)
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        StrBuf::<N>::capacity()
+    }
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        StrBuf::clear(self)
+    }
+
+    #[inline(always)]
+    fn truncate(&mut self, new_len: usize) {
+        self.truncate_str(new_len)
+    }
+}
+
+impl fmt::Write for dyn StrBufLike + '_ {
+    #[inline(always)]
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        if self.push_str(text) == text.len() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
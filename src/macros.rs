@@ -0,0 +1,107 @@
+#[macro_export]
+///Creates `StrBuf` from string literal, with capacity computed from its length.
+///
+///Optionally accepts desired capacity as first argument: `str_buf!(CAP; "text")`.
+///
+///Buffer is built in const context, so text that doesn't fit fails compilation.
+///
+///```
+///use str_buf::{str_buf, StrBuf};
+///
+///const GREETING: StrBuf<12> = str_buf!("hello world");
+///assert_eq!(GREETING, "hello world");
+///assert_eq!(core::mem::size_of_val(&GREETING), 12);
+///
+///let mut text = str_buf!(32; "hello");
+///assert_eq!(text.remaining(), 27);
+///text.push_str(" world");
+///assert_eq!(text, "hello world");
+///```
+macro_rules! str_buf {
+    ($text:expr) => {{
+        const BUF: $crate::StrBuf::<{ $crate::capacity($text.len()) }> = $crate::StrBuf::from_str($text);
+        BUF
+    }};
+    ($cap:expr; $text:expr) => {{
+        const BUF: $crate::StrBuf::<{ $crate::capacity($cap) }> = $crate::StrBuf::from_str($text);
+        BUF
+    }};
+}
+
+#[macro_export]
+///Expands to `StrBuf` type able to hold exactly specified number of bytes.
+///
+///Usable anywhere type is expected: type aliases, struct fields and so on.
+///
+///```
+///struct Packet {
+///    name: str_buf::with_capacity!(16),
+///}
+///
+///type Name = str_buf::with_capacity!(16);
+///str_buf::assert_capacity!(Name, 16);
+///
+///let packet = Packet {
+///    name: Name::from_str("0123456789abcdef"),
+///};
+///assert_eq!(packet.name.remaining(), 0);
+///```
+macro_rules! with_capacity {
+    ($cap:expr) => {
+        $crate::StrBuf::<{ $crate::capacity($cap) }>
+    };
+}
+
+#[macro_export]
+///Asserts at compile time that `StrBuf` type can hold exactly specified number of bytes.
+///
+///```compile_fail
+///str_buf::assert_capacity!(str_buf::StrBuf<16>, 16);
+///```
+macro_rules! assert_capacity {
+    ($type:ty, $cap:expr) => {
+        const _: () = assert!(<$type>::capacity() == $cap, "StrBuf capacity mismatch");
+    };
+}
+
+#[macro_export]
+///Concatenates string literals or `const` strings into `StrBuf` of exact capacity at compile time.
+///
+///```
+///use str_buf::StrBuf;
+///
+///const SEP: &str = " ";
+///const TEXT: StrBuf<12> = str_buf::concat!("hello", SEP, "world");
+///assert_eq!(TEXT, "hello world");
+///```
+///
+///Runtime values cannot be used:
+///
+///```compile_fail
+///let world = String::from("world");
+///let _ = str_buf::concat!("hello", world.as_str());
+///```
+macro_rules! concat {
+    ($($part:expr),+ $(,)?) => {{
+        const BUF: $crate::StrBuf::<{ $crate::capacity(0 $(+ $part.len())+) }> = $crate::StrBuf::new()$(.and($part))+;
+        BUF
+    }};
+}
+
+#[macro_export]
+///Creates `StrBuf` from compile time environment variable, with capacity computed from its length.
+///
+///Optionally accepts desired capacity as first argument, failing compilation if value does not fit.
+///
+///```
+///let version = str_buf::const_env!("CARGO_PKG_VERSION");
+///assert_eq!(version, env!("CARGO_PKG_VERSION"));
+///```
+macro_rules! const_env {
+    ($name:literal) => {
+        $crate::str_buf!(env!($name))
+    };
+    ($cap:expr; $name:literal) => {
+        $crate::str_buf!($cap; env!($name))
+    };
+}
@@ -0,0 +1,58 @@
+use core::{ops, mem};
+
+use crate::StrBuf;
+
+///Transaction guard over `StrBuf`, created by `StrBuf::transaction`.
+///
+///Truncates buffer back to its starting length on drop (including panic unwinding), unless `commit` is called.
+///
+///Edits to content written before transaction (e.g. `clear`) are not undone.
+pub struct StrBufTransaction<'a, const N: usize> {
+    buf: &'a mut StrBuf<N>,
+    checkpoint: usize,
+}
+
+impl<'a, const N: usize> StrBufTransaction<'a, N> {
+    #[inline(always)]
+    pub(crate) fn new(buf: &'a mut StrBuf<N>) -> Self {
+        Self {
+            checkpoint: buf.checkpoint(),
+            buf,
+        }
+    }
+
+    #[inline(always)]
+    ///Keeps all changes made during transaction.
+    pub fn commit(self) {
+        mem::forget(self);
+    }
+
+    #[inline(always)]
+    ///Truncates buffer back to its length at the start of transaction.
+    pub fn rollback(self) {
+        drop(self);
+    }
+}
+
+impl<'a, const N: usize> Drop for StrBufTransaction<'a, N> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.buf.rollback(self.checkpoint);
+    }
+}
+
+impl<'a, const N: usize> ops::Deref for StrBufTransaction<'a, N> {
+    type Target = StrBuf<N>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.buf
+    }
+}
+
+impl<'a, const N: usize> ops::DerefMut for StrBufTransaction<'a, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buf
+    }
+}
@@ -0,0 +1,169 @@
+use core::{fmt, str};
+
+use crate::StrBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Outcome of `Utf8Accumulator::push_bytes`
+pub struct PushBytes {
+    ///Number of input bytes consumed, either written into buffer or kept as incomplete character.
+    pub consumed: usize,
+    ///Number of bytes currently kept as incomplete character, waiting for more data.
+    pub incomplete: usize,
+    ///Indicates that buffer ran out of capacity, leaving rest of input unconsumed.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Invalid UTF-8 sequence encountered by `Utf8Accumulator::push_bytes`
+pub struct Utf8PushError {
+    ///Number of input bytes consumed before invalid sequence.
+    pub consumed: usize,
+    ///Number of input bytes that belong to invalid sequence.
+    ///
+    ///Can be `0` if invalid sequence consists only of previously kept incomplete bytes.
+    ///Input can be resumed from `consumed + invalid_len`.
+    pub invalid_len: usize,
+}
+
+impl fmt::Display for Utf8PushError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("Invalid UTF-8 sequence after {} bytes", self.consumed))
+    }
+}
+
+#[inline(always)]
+const fn utf8_char_width(lead: u8) -> usize {
+    if lead < 0x80 {
+        1
+    } else if lead < 0xE0 {
+        2
+    } else if lead < 0xF0 {
+        3
+    } else {
+        4
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+///Streaming UTF-8 decoder, appending chunked bytes into `StrBuf`.
+///
+///Keeps incomplete trailing character (up to 3 bytes) between calls, so that character split across chunks is decoded once rest of it arrives.
+pub struct Utf8Accumulator {
+    pending: [u8; 4],
+    pending_len: usize,
+}
+
+impl Utf8Accumulator {
+    #[inline]
+    ///Creates new instance with no pending bytes.
+    pub const fn new() -> Self {
+        Self {
+            pending: [0; 4],
+            pending_len: 0,
+        }
+    }
+
+    #[inline]
+    ///Returns bytes of incomplete character kept from previous calls.
+    pub fn pending(&self) -> &[u8] {
+        &self.pending[..self.pending_len]
+    }
+
+    #[inline]
+    ///Discards incomplete character, if any.
+    pub fn reset(&mut self) {
+        self.pending_len = 0;
+    }
+
+    ///Appends valid UTF-8 from `bytes` into `buf`, keeping incomplete trailing character for next call.
+    ///
+    ///On overflow writes as many complete characters as possible and reports `truncated`, leaving rest of input unconsumed.
+    ///
+    ///Returns error on invalid UTF-8 sequence, after writing everything that precedes it.
+    ///Incomplete character is discarded in this case.
+    pub fn push_bytes<const N: usize>(&mut self, buf: &mut StrBuf<N>, bytes: &[u8]) -> Result<PushBytes, Utf8PushError> {
+        let mut consumed = 0;
+
+        if self.pending_len > 0 {
+            let width = utf8_char_width(self.pending[0]);
+            let pending_len = self.pending_len;
+            let taken = core::cmp::min(width - pending_len, bytes.len());
+            self.pending[pending_len..pending_len + taken].copy_from_slice(&bytes[..taken]);
+
+            match str::from_utf8(&self.pending[..pending_len + taken]) {
+                Ok(ch) => {
+                    if buf.push_str(ch) == 0 {
+                        return Ok(PushBytes {
+                            consumed: 0,
+                            incomplete: pending_len,
+                            truncated: true,
+                        });
+                    }
+                    self.pending_len = 0;
+                    consumed = taken;
+                },
+                Err(error) => match error.error_len() {
+                    Some(invalid_len) => {
+                        self.pending_len = 0;
+                        return Err(Utf8PushError {
+                            consumed: 0,
+                            invalid_len: invalid_len.saturating_sub(pending_len),
+                        });
+                    },
+                    None => {
+                        self.pending_len = pending_len + taken;
+                        return Ok(PushBytes {
+                            consumed: taken,
+                            incomplete: self.pending_len,
+                            truncated: false,
+                        });
+                    }
+                },
+            }
+        }
+
+        let rest = &bytes[consumed..];
+        let (valid, error) = match str::from_utf8(rest) {
+            Ok(valid) => (valid, None),
+            Err(error) => {
+                let valid = unsafe {
+                    str::from_utf8_unchecked(&rest[..error.valid_up_to()])
+                };
+                (valid, Some(error))
+            }
+        };
+
+        let written = buf.push_str(valid);
+        consumed += written;
+        if written < valid.len() {
+            return Ok(PushBytes {
+                consumed,
+                incomplete: 0,
+                truncated: true,
+            });
+        }
+
+        match error.map(|error| error.error_len()) {
+            None => Ok(PushBytes {
+                consumed,
+                incomplete: 0,
+                truncated: false,
+            }),
+            Some(None) => {
+                let tail = &rest[written..];
+                self.pending[..tail.len()].copy_from_slice(tail);
+                self.pending_len = tail.len();
+                Ok(PushBytes {
+                    consumed: consumed + tail.len(),
+                    incomplete: tail.len(),
+                    truncated: false,
+                })
+            },
+            Some(Some(invalid_len)) => Err(Utf8PushError {
+                consumed,
+                invalid_len,
+            }),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use str_buf::StrBuf;
+
+use core::cmp::Ordering;
+
+type MediumStr = StrBuf<290>;
+
+#[test]
+fn should_compare_ignoring_case() {
+    let buf = MediumStr::from_str("ÉCOLE-Ünïcode");
+    assert!(buf.eq_ignore_case("école-ünÏCODE"));
+    assert!(!buf.eq_ignore_case("ecole-unicode"));
+    assert!(!buf.eq_ignore_case("école-ünïcod"));
+    assert_eq!(buf.cmp_ignore_case("école-ünÏCODE"), Ordering::Equal);
+
+    //'İ' lower case mapping consists of two characters
+    let buf = MediumStr::from_str("İ");
+    assert!(buf.eq_ignore_case("i\u{307}"));
+
+    let keys = ["alpha", "Beta", "ÇAVA", "delta"];
+    let buf = MediumStr::from_str("BETA");
+    assert_eq!(keys.binary_search_by(|key| MediumStr::from_str(key).cmp_ignore_case(&buf)), Ok(1));
+    assert_eq!(MediumStr::from_str("a").cmp_ignore_case("B"), Ordering::Less);
+    assert_eq!(MediumStr::from_str("b").cmp_ignore_case("A"), Ordering::Greater);
+    assert_eq!(MediumStr::from_str("").cmp_ignore_case(""), Ordering::Equal);
+}
+
+#[test]
+fn should_compare_in_const() {
+    const TEXT: MediumStr = MediumStr::new().and("hello").and(" ").and("world");
+    const _: () = assert!(TEXT.const_eq("hello world"));
+    const _: () = assert!(!TEXT.const_eq("hello worl"));
+    const _: () = assert!(!TEXT.const_eq("hello world!"));
+    const _: () = assert!(TEXT.const_eq_buf(&StrBuf::<12>::from_str("hello world")));
+    const _: () = assert!(!TEXT.const_eq_buf(&StrBuf::<12>::from_str("hello_world")));
+
+    assert!(MediumStr::new().const_eq(""));
+    assert!(StrBuf::<0>::new().const_eq_buf(&MediumStr::new()));
+    assert!(!MediumStr::from_str("ロ").const_eq("リ"));
+}
+
+#[test]
+fn should_check_prefix_suffix_in_const() {
+    const CMD: MediumStr = MediumStr::from_str("AT+RST\r\n");
+    const _: () = assert!(CMD.const_starts_with("AT+"));
+    const _: () = assert!(CMD.const_ends_with("\r\n"));
+    const _: () = assert!(!CMD.const_starts_with("AT-"));
+    const _: () = assert!(!CMD.const_ends_with("\n\n"));
+
+    assert!(CMD.const_starts_with(""));
+    assert!(CMD.const_ends_with(""));
+    assert!(MediumStr::new().const_starts_with(""));
+    assert!(MediumStr::new().const_ends_with(""));
+    assert!(!MediumStr::from_str("AT").const_starts_with("AT+"));
+    assert!(!MediumStr::from_str("AT").const_ends_with("+AT"));
+    assert!(MediumStr::from_str("ロリ").const_ends_with("リ"));
+}
+
+#[test]
+fn should_find_substring_in_const() {
+    const CONFIG: MediumStr = MediumStr::from_str("port=1;baud=9600");
+    const _: () = assert!(CONFIG.const_contains("baud="));
+    const _: () = assert!(!CONFIG.const_contains("parity="));
+    const BAUD: Option<usize> = CONFIG.const_find("baud=");
+
+    assert_eq!(BAUD, Some(7));
+    assert_eq!(CONFIG.const_find("9600"), Some(12));
+    assert_eq!(CONFIG.const_find("9600!"), None);
+    assert_eq!(CONFIG.const_find(""), Some(0));
+    assert!(MediumStr::new().const_contains(""));
+    assert_eq!(MediumStr::new().const_find("a"), None);
+    assert_eq!(MediumStr::from_str("ロリリ").const_find("リ"), Some(3));
+}
+
+#[test]
+fn should_compare_across_capacities() {
+    fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+        use core::hash::Hasher;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let small = StrBuf::<16>::from_str("ロリ");
+    let medium = MediumStr::from_str("ロリ");
+    assert_eq!(small, medium);
+    assert_eq!(medium, small);
+    assert_eq!(hash(&small), hash(&medium));
+    assert_eq!(small.partial_cmp(&medium), Some(Ordering::Equal));
+
+    let other = StrBuf::<4>::from_str("ロ");
+    assert_ne!(other, medium);
+    assert!(other < medium);
+    assert!(medium > other);
+    assert_eq!(medium.partial_cmp(&other), Some("ロリ".cmp("ロ")));
+    assert!(StrBuf::<0>::new() < other);
+    assert_eq!(small.cmp(&StrBuf::<16>::from_str("ロ")), Ordering::Greater);
+}
+
+#[test]
+fn should_order_against_str() {
+    let buf = MediumStr::from_str("ロa");
+    assert!(buf > "zzz");
+    assert!(MediumStr::from_str("abc") < "zzz");
+    assert!(buf > "ロ");
+    assert!("ロ" < buf);
+    assert!(*"ロb" > buf);
+    assert!(buf <= "ロa");
+
+    let keys = ["a", "ab", "b", "ロ", "ロa", "ロb", ""];
+    for key in keys.iter() {
+        assert_eq!(buf.partial_cmp(key), Some("ロa".cmp(key)));
+        assert_eq!(buf.partial_cmp(*key), Some("ロa".cmp(key)));
+        assert_eq!(key.partial_cmp(&buf), Some(key.cmp(&"ロa")));
+        assert_eq!((*key).partial_cmp(&buf), Some(key.cmp(&"ロa")));
+    }
+
+    let sorted = [MediumStr::from_str("a"), MediumStr::from_str("c"), MediumStr::from_str("e")];
+    assert_eq!(sorted.binary_search_by(|item| item.partial_cmp("c").unwrap()), Ok(1));
+}
+
+#[test]
+fn should_compare_against_bytes() {
+    let buf = MediumStr::from_str("AT+ロ");
+    let token: &[u8] = "AT+ロ".as_bytes();
+    assert_eq!(buf, token);
+    assert_eq!(token, buf);
+    assert!(buf == *token);
+    assert!(*token == buf);
+    assert_ne!(buf, &token[..3]);
+    assert_ne!(&token[..3], buf);
+
+    let magic = MediumStr::from_str("MAGI");
+    assert_eq!(magic, *b"MAGI");
+    assert_eq!(*b"MAGI", magic);
+    assert_ne!(magic, *b"MAGIC");
+    assert_ne!(*b"MAG", magic);
+    assert_eq!(MediumStr::new(), [0u8; 0]);
+}
+
+#[test]
+fn should_compare_case_insensitive_keys() {
+    use std::collections::{BTreeMap, HashMap};
+    use str_buf::CaseInsensitive;
+
+    fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+        use core::hash::Hasher;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let upper = CaseInsensitive::from(MediumStr::from_str("Content-Type"));
+    let lower = CaseInsensitive(MediumStr::from_str("content-type"));
+    assert_eq!(upper, lower);
+    assert_eq!(hash(&upper), hash(&lower));
+    assert_eq!(upper.cmp(&lower), Ordering::Equal);
+    assert_eq!(upper.as_str(), "Content-Type");
+    assert_eq!(upper.into_inner(), "Content-Type");
+
+    let mut map = HashMap::new();
+    map.insert(upper, 1);
+    map.insert(lower, 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&CaseInsensitive(MediumStr::from_str("CONTENT-TYPE"))], 2);
+
+    let mut table = BTreeMap::new();
+    table.insert(CaseInsensitive(MediumStr::from_str("b")), 1);
+    table.insert(CaseInsensitive(MediumStr::from_str("A")), 2);
+    table.insert(CaseInsensitive(MediumStr::from_str("_")), 3);
+    let keys: Vec<&str> = table.keys().map(|key| key.as_str()).collect();
+    assert_eq!(keys, ["_", "A", "b"]);
+
+    assert_ne!(CaseInsensitive(MediumStr::from_str("ÄB")), CaseInsensitive(MediumStr::from_str("äb")));
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "const-mut")]
+
+use str_buf::StrBuf;
+
+type SmolStr = StrBuf<6>;
+type MediumStr = StrBuf<290>;
+type BigStr = StrBuf<67_000>;
+
+const fn write_greeting<const N: usize>(buf: &mut StrBuf<N>, name: &str) {
+    buf.push_str_const("hello, ");
+    buf.push_str_const(name);
+}
+
+#[test]
+fn should_push_in_const() {
+    const GREETING: MediumStr = {
+        let mut buf = MediumStr::new();
+        write_greeting(&mut buf, "ロリ");
+        buf
+    };
+    assert_eq!(GREETING, "hello, ロリ");
+
+    const TRUNCATED: SmolStr = {
+        let mut buf = SmolStr::new();
+        assert!(buf.push_str_const("abc") == 3);
+        assert!(buf.push_str_const("ロ") == 0);
+        assert!(buf.push_str_const("de") == 2);
+        buf
+    };
+    assert_eq!(TRUNCATED, "abcde");
+
+    const BIG: BigStr = {
+        let mut buf = BigStr::new();
+        write_greeting(&mut buf, "world");
+        buf
+    };
+    assert_eq!(BIG, "hello, world");
+}
+
+#[test]
+fn should_clear_in_const() {
+    const CLEARED: MediumStr = {
+        let mut buf = MediumStr::new();
+        buf.push_str_const("garbage");
+        buf.clear_const();
+        buf.push_str_const("ok");
+        buf
+    };
+    assert_eq!(CLEARED, "ok");
+
+    let mut buf = SmolStr::from_str("abc");
+    buf.clear_const();
+    assert!(buf.is_empty());
+}
@@ -0,0 +1,485 @@
+use str_buf::StrBuf;
+
+type SmolStr = StrBuf<6>;
+type MediumStr = StrBuf<290>;
+type BigStr = StrBuf<67_000>;
+
+#[test]
+fn should_join_parts() {
+    let buf = MediumStr::join(["serde", "ufmt-write", "ロリ"], ", ").expect("Should fit");
+    assert_eq!(buf, "serde, ufmt-write, ロリ");
+    let buf = MediumStr::join(core::iter::empty::<&str>(), ", ").expect("Should fit");
+    assert_eq!(buf, "");
+    let buf = BigStr::join(["single"], ", ").expect("Should fit");
+    assert_eq!(buf, "single");
+
+    let parts = [SmolStr::from_str("ab"), SmolStr::from_str("ロ")];
+    let buf = MediumStr::join(parts.iter(), "|").expect("Should fit");
+    assert_eq!(buf, "ab|ロ");
+    let buf = MediumStr::join(parts, "").expect("Should fit");
+    assert_eq!(buf, "abロ");
+
+    SmolStr::join(["ab", "ロ"], "|").expect_err("Should overflow on last part");
+    SmolStr::join(["ab", "c"], "ロ").expect_err("Should overflow on separator");
+    let buf = SmolStr::join(["ab", "c"], "||").expect("Fits exactly");
+    assert_eq!(buf, "ab||c");
+}
+
+#[test]
+fn should_create_from_fn() {
+    let text = SmolStr::from_fn(|region| {
+        assert_eq!(region.len(), SmolStr::capacity());
+        for (dst, src) in region.iter_mut().zip("ロリ".bytes()) {
+            *dst = core::mem::MaybeUninit::new(src);
+        }
+        3
+    }).expect("Should fit");
+    assert_eq!(text, "ロ");
+
+    let empty = MediumStr::from_fn(|_| 0).expect("Should be empty");
+    assert_eq!(empty, "");
+
+    let text = BigStr::from_fn(|region| {
+        region[0] = core::mem::MaybeUninit::new(b'a');
+        2
+    }).expect("Zeroed region is valid");
+    assert_eq!(text, "a\0");
+
+    let error = SmolStr::from_fn(|_| SmolStr::capacity() + 1).expect_err("Should overflow");
+    assert!(matches!(error, str_buf::StrBufError::Overflow));
+
+    let error = SmolStr::from_fn(|region| {
+        region[0] = core::mem::MaybeUninit::new(b'a');
+        region[1] = core::mem::MaybeUninit::new(0xFF);
+        2
+    }).expect_err("Should be invalid UTF-8");
+    match error {
+        str_buf::StrBufError::Utf8(error) => assert_eq!(error.valid_up_to(), 1),
+        error => panic!("Unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn should_convert_from_utf8() {
+    use core::convert::TryFrom;
+    use str_buf::StrBufError;
+
+    let text = SmolStr::try_from("ロリ".as_bytes()).expect_err("Should overflow");
+    assert!(matches!(text, StrBufError::Overflow));
+    assert_eq!(text.to_string(), "Buffer overflow");
+
+    let text = SmolStr::try_from(&b"a\xFFb"[..]).expect_err("Should be invalid");
+    assert!(matches!(text, StrBufError::Utf8(_)));
+    assert!(text.to_string().starts_with("Invalid UTF-8"));
+
+    let text = MediumStr::try_from("ロリ".as_bytes()).expect("Should fit");
+    assert_eq!(text, "ロリ");
+    let text = BigStr::from_utf8(b"abc").expect("Should fit");
+    assert_eq!(text, "abc");
+
+    const TEXT: SmolStr = match SmolStr::from_utf8(b"ab") {
+        Ok(text) => text,
+        Err(_) => panic!("Invalid text"),
+    };
+    assert_eq!(TEXT, "ab");
+}
+
+#[test]
+fn should_create_from_ascii() {
+    use str_buf::StrBufError;
+
+    const HEADER: MediumStr = MediumStr::from_ascii("HTTP/1.1");
+    const _: () = assert!(HEADER.is_ascii());
+    assert_eq!(HEADER, "HTTP/1.1");
+
+    let text = SmolStr::from_ascii_checked("abc").expect("Should be ASCII");
+    assert!(text.is_ascii());
+    assert!(matches!(SmolStr::from_ascii_checked("aロ"), Err(StrBufError::NotAscii)));
+    assert!(matches!(SmolStr::from_ascii_checked("abcdef"), Err(StrBufError::Overflow)));
+
+    let text = BigStr::from_str("ロリ");
+    assert!(!text.is_ascii());
+    assert!(BigStr::new().is_ascii());
+}
+
+#[test]
+fn should_create_from_char() {
+    use core::convert::TryFrom;
+
+    const ASCII: SmolStr = SmolStr::from_char('a');
+    assert_eq!(ASCII, "a");
+    assert_eq!(SmolStr::from_char('ß'), "ß");
+    assert_eq!(MediumStr::from_char('ロ'), "ロ");
+    assert_eq!(BigStr::from_char('😀'), "😀");
+
+    assert_eq!(SmolStr::try_from('😀').expect("Should fit"), "😀");
+    StrBuf::<0>::try_from('a').expect_err("Should overflow");
+    StrBuf::<3>::try_from('ロ').expect_err("Should overflow");
+    assert_eq!(StrBuf::<3>::try_from('ß').expect("Should fit"), "ß");
+}
+
+#[test]
+fn should_create_from_chars() {
+    use core::convert::TryFrom;
+
+    assert_eq!(SmolStr::from_chars(&['a', 'b', 'c', 'd', 'e']).expect("Should fit"), "abcde");
+    assert_eq!(SmolStr::from_chars(&[]).expect("Should fit"), "");
+    SmolStr::from_chars(&['a', 'b', 'c', 'ロ']).expect_err("Should overflow because of multibyte char");
+    assert_eq!(SmolStr::from_chars(&['a', 'ロ']).expect("Should fit"), "aロ");
+
+    let chars = ['ロ', 'リ', '😀', 'ß'];
+    assert_eq!(MediumStr::try_from(&chars[..]).expect("Should fit"), "ロリ😀ß");
+    assert_eq!(BigStr::try_from(&chars[..]).expect("Should fit"), "ロリ😀ß");
+}
+
+#[test]
+fn should_create_from_str_repeat() {
+    const LINE: StrBuf<81> = StrBuf::from_str_repeat("-", 80);
+    assert_eq!(LINE.len(), 80);
+    assert!(LINE.chars().all(|ch| ch == '-'));
+
+    assert_eq!(SmolStr::from_str_repeat("ab", 2), "abab");
+    assert_eq!(SmolStr::from_str_repeat("", 100), "");
+    assert_eq!(SmolStr::from_str_repeat("ab", 0), "");
+    assert_eq!(MediumStr::from_str_repeat("ロ", 3), "ロロロ");
+
+    assert_eq!(SmolStr::from_str_repeat_checked("a", 5).expect("Should fit"), "aaaaa");
+    SmolStr::from_str_repeat_checked("ab", 3).expect_err("Should overflow");
+    BigStr::from_str_repeat_checked("ab", usize::MAX).expect_err("Should overflow");
+}
+
+#[test]
+fn should_create_from_display_and_debug() {
+    assert_eq!(SmolStr::from_display(&12345).expect("Should fit"), "12345");
+    SmolStr::from_display(&123456).expect_err("Should overflow");
+    assert_eq!(MediumStr::from_display("ロリ").expect("Should fit"), "ロリ");
+
+    assert_eq!(SmolStr::from_debug("abc").expect("Should fit"), "\"abc\"");
+    SmolStr::from_debug("abcd").expect_err("Should overflow");
+    assert_eq!(BigStr::from_debug(&Some(1)).expect("Should fit"), "Some(1)");
+}
+
+#[test]
+fn should_format_arguments() {
+    use core::convert::TryFrom;
+
+    let host = "local";
+    let port = 80;
+    assert_eq!(MediumStr::format(format_args!("{}:{}", host, port)).expect("Should fit"), "local:80");
+    SmolStr::format(format_args!("{}:{}", host, port)).expect_err("Should overflow");
+    assert_eq!(BigStr::try_from(format_args!("{}", 'ロ')).expect("Should fit"), "ロ");
+    SmolStr::try_from(format_args!("{}{}", "ロ", "リ")).expect_err("Should overflow");
+}
+
+#[test]
+fn should_format_lossy() {
+    let text = SmolStr::format_lossy(format_args!("{}-{}", 1234, 5));
+    assert_eq!(text, "1234-");
+    let text = SmolStr::format_lossy(format_args!("{}{}{}", "abcロ", 1, 2));
+    assert_eq!(text, "abc");
+    assert!("abcロ12".starts_with(text.as_str()));
+    let text = MediumStr::format_lossy(format_args!("{}:{}", "ロ", 80));
+    assert_eq!(text, "ロ:80");
+    let text = StrBuf::<0>::format_lossy(format_args!("{}", 1));
+    assert_eq!(text, "");
+}
+
+#[test]
+fn should_validate_utf8_in_const() {
+    const TEXT: MediumStr = MediumStr::from_utf8_unwrap("ロリ".as_bytes());
+    assert_eq!(TEXT, "ロリ");
+    const RESULT: Result<SmolStr, str_buf::StrBufError> = SmolStr::from_utf8_const(b"abc");
+    assert_eq!(RESULT.expect("Should be valid"), "abc");
+
+    let invalid: &[&[u8]] = &[
+        //overlong
+        b"\xC0\x80",
+        b"\xC1\xBF",
+        b"\xE0\x80\x80",
+        b"\xF0\x80\x80\x80",
+        //surrogates
+        b"\xED\xA0\x80",
+        b"\xED\xBF\xBF",
+        //above U+10FFFF
+        b"\xF4\x90\x80\x80",
+        b"\xF5\x80\x80\x80",
+        //unexpected continuation
+        b"\x80",
+        b"a\xBF",
+        //truncated sequence
+        b"\xE3\x83",
+        b"\xF0\x9F\x98",
+        //invalid byte
+        b"\xFF",
+    ];
+    for bytes in invalid {
+        match BigStr::from_utf8_const(bytes) {
+            Err(str_buf::StrBufError::Utf8(_)) => (),
+            result => panic!("{:?} should be invalid, got {:?}", bytes, result),
+        }
+    }
+
+    SmolStr::from_utf8_const("ロリ".as_bytes()).expect_err("Should overflow");
+}
+
+#[test]
+fn should_append_integers_in_const() {
+    const VER: MediumStr = MediumStr::new().and("v").and_dec(2).and(".").and_dec(13);
+    assert_eq!(VER, "v2.13");
+    const ZERO: SmolStr = SmolStr::new().and_dec(0).and_hex(0, false);
+    assert_eq!(ZERO, "00");
+
+    assert_eq!(MediumStr::new().and_dec(u64::MAX), u64::MAX.to_string().as_str());
+    assert_eq!(MediumStr::new().and_dec_signed(i64::MIN), i64::MIN.to_string().as_str());
+    assert_eq!(MediumStr::new().and_dec_signed(-1).and_dec_signed(1), "-11");
+
+    assert_eq!(MediumStr::new().and_hex(u64::MAX, false), "ffffffffffffffff");
+    assert_eq!(MediumStr::new().and_hex(0xABCD, true), "ABCD");
+    assert_eq!(BigStr::new().and_hex(0x1F, false).len(), 2);
+    assert_eq!(SmolStr::new().and("0x").and_hex(0xFF, true), "0xFF");
+}
+
+#[test]
+fn should_append_char_in_const() {
+    const DIGIT: u8 = 7;
+    const TEXT: MediumStr = MediumStr::new().and_char('a').and_char('ß').and_char('ロ').and_char('😀').and_char((b'0' + DIGIT) as char);
+    assert_eq!(TEXT, "aßロ😀7");
+    assert_eq!(TEXT.len(), 11);
+
+    assert_eq!(SmolStr::new().and_char('ロ').and_char('ß'), "ロß");
+    assert_eq!(BigStr::from_str("line").and_char('\n'), "line\n");
+}
+
+#[test]
+fn should_append_buf_in_const() {
+    const PREFIX: SmolStr = SmolStr::from_str("ERR: ");
+    const TIMEOUT: MediumStr = MediumStr::new().and_buf(&PREFIX).and("timeout");
+    const OVERFLOW: MediumStr = MediumStr::new().and_buf(&PREFIX).and_buf(&StrBuf::<9>::from_str("overflow"));
+    assert_eq!(TIMEOUT, "ERR: timeout");
+    assert_eq!(OVERFLOW, "ERR: overflow");
+
+    const CHECKED: Result<SmolStr, str_buf::StrBufError> = SmolStr::new().and_buf_checked(&PREFIX);
+    assert_eq!(CHECKED.expect("Should fit"), "ERR: ");
+    SmolStr::from_str("a").and_buf_checked(&PREFIX).expect_err("Should overflow");
+    assert_eq!(BigStr::new().and_buf_checked(&TIMEOUT).expect("Should fit"), "ERR: timeout");
+}
+
+#[test]
+fn should_concat_in_const() {
+    const HOST: SmolStr = SmolStr::from_str("host");
+    const PORT: StrBuf<4> = StrBuf::from_str(":80");
+    const ADDR: StrBuf<8> = StrBuf::concat(&HOST, &PORT);
+    assert_eq!(ADDR, "host:80");
+    assert_eq!(ADDR.len(), 7);
+    assert!(ADDR.is_full());
+
+    let text = BigStr::concat(&MediumStr::from_str("ロ"), &SmolStr::new());
+    assert_eq!(text, "ロ");
+    assert_eq!(text.len(), 3);
+}
+
+#[test]
+fn should_append_checked_in_const() {
+    const BANNER: SmolStr = match SmolStr::from_str("v").and_checked("1.2.3") {
+        Ok(text) => text,
+        Err(_) => SmolStr::from_str("v").and("1.2"),
+    };
+    assert_eq!(BANNER, "v1.2");
+
+    assert_eq!(SmolStr::new().and_checked("abcde").expect("Should fit"), "abcde");
+    SmolStr::new().and_checked("abcdef").expect_err("Should overflow");
+    assert_eq!(MediumStr::from_str("ロ").and_checked("リ").expect("Should fit"), "ロリ");
+}
+
+#[test]
+fn should_append_lossy_in_const() {
+    const BANNER: SmolStr = SmolStr::from_str("ab").and_lossy("cロ");
+    assert_eq!(BANNER, "abc");
+    assert_eq!(BANNER.len(), 3);
+
+    assert_eq!(SmolStr::new().and_lossy("abcdefg"), "abcde");
+    assert_eq!(SmolStr::from_str("abcde").and_lossy("f"), "abcde");
+    assert_eq!(SmolStr::from_str("ab").and_lossy("ロ"), "abロ");
+    assert_eq!(StrBuf::<0>::new().and_lossy("ロ"), "");
+    assert_eq!(MediumStr::new().and_lossy("ロリ"), "ロリ");
+}
+
+#[test]
+fn should_resize() {
+    const SMALL: SmolStr = SmolStr::from_str("aロ");
+    const MEDIUM: MediumStr = SMALL.resize();
+    const BIG: BigStr = MEDIUM.resize();
+    const EXACT: StrBuf<5> = BIG.resize();
+
+    assert_eq!(MEDIUM, "aロ");
+    assert_eq!(BIG, "aロ");
+    assert_eq!(EXACT, "aロ");
+    assert!(EXACT.is_full());
+    assert_eq!(BigStr::new().resize::<0>(), "");
+}
+
+#[test]
+fn should_try_resize() {
+    use core::convert::TryFrom;
+
+    let small = SmolStr::from_str("aロ");
+    let medium = MediumStr::try_from(&small).expect("Should grow");
+    assert_eq!(medium, "aロ");
+    let exact = StrBuf::<5>::try_from(&medium).expect("Should shrink with fit");
+    assert_eq!(exact, "aロ");
+    StrBuf::<4>::try_from(&medium).expect_err("Should not fit");
+    medium.try_resize::<0>().expect_err("Should not fit");
+
+    let empty = StrBuf::<0>::try_from(&BigStr::new()).expect("Empty fits");
+    assert_eq!(empty, "");
+    let big = BigStr::try_from(&empty).expect("Should grow");
+    assert_eq!(big, "");
+    assert_eq!(SmolStr::try_from(&small).expect("Same capacity"), small);
+}
+
+#[test]
+fn should_export_to_array() {
+    const TEXT: SmolStr = SmolStr::from_str("aロ");
+    const ARRAY: ([u8; 8], usize) = TEXT.to_array();
+    assert_eq!(ARRAY.1, 4);
+    assert_eq!(&ARRAY.0[..4], "aロ".as_bytes());
+    assert_eq!(&ARRAY.0[4..], &[0; 4]);
+
+    let (array, len) = TEXT.to_array::<4>();
+    assert_eq!(&array, "aロ".as_bytes());
+    assert_eq!(len, 4);
+
+    TEXT.to_array_checked::<3>().expect_err("Should not fit");
+    let (array, len) = BigStr::from_str("ab").to_array_checked::<2>().expect("Should fit");
+    assert_eq!((array, len), (*b"ab", 2));
+    assert_eq!(MediumStr::new().to_array::<0>(), ([], 0));
+}
+
+#[test]
+fn should_roundtrip_storage() {
+    const STORAGE: [core::mem::MaybeUninit<u8>; 290] = MediumStr::from_str("ロリ").into_storage();
+    let text = unsafe {
+        MediumStr::from_storage_validated(STORAGE)
+    }.expect("Should be valid");
+    assert_eq!(text, "ロリ");
+
+    let mut storage = SmolStr::from_str("abc").into_storage();
+    storage[SmolStr::LEN_OFFSET + 1] = core::mem::MaybeUninit::new(0xFF);
+    match unsafe { SmolStr::from_storage_validated(storage) } {
+        Err(str_buf::StrBufError::Utf8(error)) => assert_eq!(error.valid_up_to(), 1),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+
+    let mut storage = SmolStr::from_str("abc").into_storage();
+    storage[0] = core::mem::MaybeUninit::new(6);
+    assert!(matches!(unsafe { SmolStr::from_storage_validated(storage) }, Err(str_buf::StrBufError::Overflow)));
+
+    let storage = BigStr::from_str("big").into_storage();
+    assert_eq!(unsafe { BigStr::from_storage_validated(storage) }.expect("Should be valid"), "big");
+    let storage = StrBuf::<0>::new().into_storage();
+    assert_eq!(unsafe { StrBuf::<0>::from_storage_validated(storage) }.expect("Should be valid"), "");
+}
+
+#[test]
+fn should_roundtrip_wire_format() {
+    use str_buf::StrBufError;
+
+    let text = SmolStr::from_str("aロ");
+    let mut wire = [0u8; 8];
+    assert_eq!(text.wire_len(), 5);
+    assert_eq!(text.write_wire(&mut wire).expect("Should fit"), 5);
+    assert_eq!(&wire[..5], b"\x04a\xE3\x83\xAD");
+    assert_eq!(SmolStr::from_wire(&wire).expect("Should decode"), text);
+    text.write_wire(&mut wire[..4]).expect_err("Should not fit");
+
+    let mut text = BigStr::new();
+    text.extend_with('ロ', 22_000);
+    let mut wire = [0u8; 66_010];
+    let size = text.write_wire(&mut wire).expect("Should fit");
+    assert_eq!(size, 3 + 66_000);
+    assert_eq!(&wire[..3], &[0xD0, 0x83, 0x04]);
+    assert_eq!(BigStr::from_wire(&wire[..size]).expect("Should decode"), text);
+    assert!(matches!(MediumStr::from_wire(&wire), Err(StrBufError::Overflow)));
+
+    let empty = StrBuf::<0>::new();
+    let mut wire = [0xFF; 1];
+    assert_eq!(empty.write_wire(&mut wire).expect("Should fit"), 1);
+    assert_eq!(wire, [0]);
+    assert_eq!(StrBuf::<0>::from_wire(&wire).expect("Should decode"), "");
+
+    assert!(matches!(SmolStr::from_wire(&[]), Err(StrBufError::InvalidWire)));
+    assert!(matches!(SmolStr::from_wire(&[0x80]), Err(StrBufError::InvalidWire)));
+    assert!(matches!(SmolStr::from_wire(b"\x03ab"), Err(StrBufError::InvalidWire)));
+    assert!(matches!(SmolStr::from_wire(&[0xFF; 16]), Err(StrBufError::InvalidWire)));
+    assert!(matches!(SmolStr::from_wire(b"\x02a\xFF"), Err(StrBufError::Utf8(_))));
+}
+
+#[test]
+fn should_collect_from_iter() {
+    let text: SmolStr = "a1b2c3d4e5".chars().filter(char::is_ascii_alphabetic).collect();
+    assert_eq!(text, "abcde");
+    let text: SmolStr = "abロリ".chars().collect();
+    assert_eq!(text, "abロ");
+    let text: SmolStr = "abcロa".chars().collect();
+    assert_eq!(text, "abc");
+    let text: SmolStr = core::iter::empty::<char>().collect();
+    assert_eq!(text, "");
+
+    let text: SmolStr = ["ab", "cde"].iter().copied().collect();
+    assert_eq!(text, "abcde");
+    let text: SmolStr = ["ab", "cロ", "d"].iter().copied().collect();
+    assert_eq!(text, "abc");
+    let text: MediumStr = core::iter::empty::<&str>().collect();
+    assert_eq!(text, "");
+
+    let parts = [SmolStr::from_str("ロ"), SmolStr::from_str("リ")];
+    let text: BigStr = parts.iter().copied().collect();
+    assert_eq!(text, "ロリ");
+    let text: SmolStr = parts.iter().copied().collect();
+    assert_eq!(text, "ロ");
+}
+
+#[test]
+fn should_try_collect_from_iter() {
+    let text = SmolStr::try_from_iter("abcde".chars()).expect("Should fit");
+    assert_eq!(text, "abcde");
+    assert_eq!(SmolStr::try_from_iter(core::iter::empty()).expect("Should fit"), "");
+
+    let mut consumed = 0;
+    SmolStr::try_from_iter("abcロd".chars().inspect(|_| consumed += 1)).expect_err("Should overflow");
+    assert_eq!(consumed, 4, "Overflow is detected on 'ロ'");
+
+    let text = MediumStr::try_from_str_iter(["ロ", "リ"].iter().copied()).expect("Should fit");
+    assert_eq!(text, "ロリ");
+
+    let mut consumed = 0;
+    SmolStr::try_from_str_iter(["ab", "cd", "ef", "g"].iter().copied().inspect(|_| consumed += 1)).expect_err("Should overflow");
+    assert_eq!(consumed, 3, "Overflow is detected on \"ef\"");
+    assert_eq!(BigStr::try_from_str_iter(["ab", "cd", "ef", "g"].iter().copied()).expect("Should fit"), "abcdefg");
+}
+
+#[test]
+fn should_encode_utf16() {
+    let text = MediumStr::from_str("aロ😀");
+    let expected: Vec<u16> = "aロ😀".encode_utf16().collect();
+    assert_eq!(expected.len(), 4);
+    assert!(text.encode_utf16().eq(expected.iter().copied()));
+
+    let (units, len) = text.to_utf16::<4>(false).expect("Should fit");
+    assert_eq!(len, 4);
+    assert_eq!(&units[..], &expected[..]);
+    assert_eq!(&units[2..], &[0xD83D, 0xDE00]);
+
+    let (units, len) = text.to_utf16::<6>(true).expect("Should fit");
+    assert_eq!(len, 5);
+    assert_eq!(&units[..4], &expected[..]);
+    assert_eq!(&units[4..], &[0, 0]);
+
+    text.to_utf16::<4>(true).expect_err("Terminator should not fit");
+    text.to_utf16::<3>(false).expect_err("Surrogate pair should not fit");
+
+    let (units, len) = SmolStr::new().to_utf16::<1>(true).expect("Should fit");
+    assert_eq!((units, len), ([0], 1));
+    assert_eq!(SmolStr::new().to_utf16::<0>(false).expect("Should fit").1, 0);
+    SmolStr::new().to_utf16::<0>(true).expect_err("Terminator should not fit");
+}
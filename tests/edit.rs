@@ -0,0 +1,690 @@
+use str_buf::StrBuf;
+
+type SmolStr = StrBuf<6>;
+type MediumStr = StrBuf<290>;
+type BigStr = StrBuf<67_000>;
+
+#[test]
+fn should_insert_str() {
+    let mut buf = SmolStr::from_str("ロ");
+    buf.insert_str(0, "r").expect("Should fit");
+    assert_eq!(buf, "rロ");
+    buf.insert_str(buf.len(), "i").expect("Should fit");
+    assert_eq!(buf, "rロi");
+    buf.insert_str(1, "..").expect_err("Should overflow");
+    assert_eq!(buf, "rロi");
+    assert_eq!(buf.len(), "rロi".len());
+
+    let mut buf = SmolStr::from_str("ロ");
+    buf.insert_str(3, "").expect("Empty string always fits");
+    buf.insert_str(3, "ri").expect("Should fit");
+    assert_eq!(buf, "ロri");
+}
+
+#[test]
+fn should_insert_str_medium() {
+    let mut buf = MediumStr::from_str("ロリ");
+    buf.insert_str(0, "<").expect("Should fit");
+    buf.insert_str(buf.len(), ">").expect("Should fit");
+    buf.insert_str(4, "and").expect("Should fit");
+    assert_eq!(buf, "<ロandリ>");
+
+    let mut buf = MediumStr::new();
+    for _ in 0..MediumStr::capacity() - 1 {
+        buf.insert_str(0, "-").expect("Should fit");
+    }
+    buf.insert_str(1, "ロ").expect_err("Should overflow");
+    buf.insert_str(1, "r").expect("Should fit");
+    assert_eq!(buf.remaining(), 0);
+    assert_eq!(&buf[..3], "-r-");
+}
+
+#[test]
+fn should_insert_str_big() {
+    let mut buf = BigStr::from_str("ロリ");
+    buf.insert_str(0, "<").expect("Should fit");
+    buf.insert_str(buf.len(), ">").expect("Should fit");
+    buf.insert_str(4, "and").expect("Should fit");
+    assert_eq!(buf, "<ロandリ>");
+
+    let padding = "-".repeat(BigStr::capacity() - buf.len());
+    buf.insert_str(4, &padding).expect("Should fit");
+    assert_eq!(buf.remaining(), 0);
+    assert!(buf.starts_with("<ロ-"));
+    assert!(buf.ends_with("-andリ>"));
+    buf.insert_str(0, "r").expect_err("Should overflow");
+}
+
+#[test]
+fn should_insert_char() {
+    let mut buf = SmolStr::from_str("ri");
+    buf.insert(1, 'ロ').expect("Exactly 3 bytes remain");
+    assert_eq!(buf, "rロi");
+    assert_eq!(buf.remaining(), 0);
+    buf.insert(0, '.').expect_err("Should overflow");
+    assert_eq!(buf, "rロi");
+
+    let mut buf = SmolStr::from_str("ロ");
+    buf.insert(buf.len(), 'r').expect("Should fit");
+    buf.insert(0, 'i').expect("Should fit");
+    assert_eq!(buf, "iロr");
+}
+
+#[test]
+fn should_insert_char_medium_and_big() {
+    let mut medium = MediumStr::from_str("ロリ");
+    let mut big = BigStr::from_str("ロリ");
+
+    medium.insert(3, '😀').expect("Should fit");
+    big.insert(3, '😀').expect("Should fit");
+    medium.insert(medium.len(), 'é').expect("Should fit");
+    big.insert(big.len(), 'é').expect("Should fit");
+    assert_eq!(medium, "ロ😀リé");
+    assert_eq!(big, "ロ😀リé");
+}
+
+#[test]
+fn should_remove_char() {
+    let mut buf = SmolStr::from_str("rロi");
+    assert_eq!(buf.remove(0), 'r');
+    assert_eq!(buf, "ロi");
+    assert_eq!(buf.remove(3), 'i');
+    assert_eq!(buf, "ロ");
+
+    let mut buf = SmolStr::from_str("rロi");
+    assert_eq!(buf.remove(1), 'ロ');
+    assert_eq!(buf, "ri");
+    assert_eq!(buf.len(), 2);
+}
+
+#[test]
+fn should_remove_char_medium() {
+    let mut buf = MediumStr::from_str("rロi");
+    assert_eq!(buf.remove(0), 'r');
+    assert_eq!(buf.remove(3), 'i');
+    assert_eq!(buf, "ロ");
+
+    let mut buf = MediumStr::from_str("rロi");
+    assert_eq!(buf.remove(1), 'ロ');
+    assert_eq!(buf, "ri");
+    assert_eq!(buf.len(), 2);
+}
+
+#[test]
+fn should_remove_char_big() {
+    let mut buf = BigStr::from_str("rロi");
+    assert_eq!(buf.remove(0), 'r');
+    assert_eq!(buf.remove(3), 'i');
+    assert_eq!(buf, "ロ");
+
+    let mut buf = BigStr::from_str("rロi");
+    assert_eq!(buf.remove(1), 'ロ');
+    assert_eq!(buf, "ri");
+    assert_eq!(buf.len(), 2);
+}
+
+#[test]
+fn should_retain_non_whitespace() {
+    let mut buf = MediumStr::from_str(" r\tロ \ni\u{a0}");
+    buf.retain(|ch| !ch.is_whitespace());
+    assert_eq!(buf, "rロi");
+
+    let mut buf = BigStr::from_str(" r\tロ \ni\u{a0}");
+    buf.retain(|ch| !ch.is_whitespace());
+    assert_eq!(buf, "rロi");
+
+    let mut buf = SmolStr::from_str("   ");
+    buf.retain(|ch| !ch.is_whitespace());
+    assert_eq!(buf, "");
+}
+
+#[test]
+fn should_retain_ascii() {
+    let mut buf = SmolStr::from_str("rロi");
+    buf.retain(|ch| ch.is_ascii());
+    assert_eq!(buf, "ri");
+
+    let mut buf = MediumStr::from_str("ロrリé😀i");
+    buf.retain(|ch| ch.is_ascii());
+    assert_eq!(buf, "ri");
+
+    let mut buf = BigStr::from_str("ロrリé😀i");
+    buf.retain(|ch| ch.is_ascii());
+    assert_eq!(buf, "ri");
+}
+
+#[test]
+fn should_keep_valid_content_when_retain_panics() {
+    let mut buf = MediumStr::from_str("ロ rリ i");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        buf.retain(|ch| if ch == 'i' {
+            panic!("boom")
+        } else {
+            !ch.is_whitespace()
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(buf, "ロrリ");
+    assert!(core::str::from_utf8(buf.as_slice()).is_ok());
+}
+
+#[test]
+fn should_replace_range() {
+    let mut buf = MediumStr::from_str("rロリi");
+    buf.replace_range(1..4, "-").expect("Shorter replacement");
+    assert_eq!(buf, "r-リi");
+    buf.replace_range(2..5, "ロ").expect("Equal replacement");
+    assert_eq!(buf, "r-ロi");
+    buf.replace_range(..1, "<😀>").expect("Longer replacement");
+    assert_eq!(buf, "<😀>-ロi");
+    buf.replace_range(buf.len().., ">").expect("Append");
+    assert_eq!(buf, "<😀>-ロi>");
+    buf.replace_range(.., "").expect("Remove everything");
+    assert_eq!(buf, "");
+
+    let mut buf = BigStr::from_str("rロリi");
+    buf.replace_range(1..=6, "é").expect("Shorter replacement");
+    assert_eq!(buf, "réi");
+}
+
+#[test]
+fn should_not_replace_range_on_overflow() {
+    let mut buf = SmolStr::from_str("rロi");
+    buf.replace_range(1..4, "-ロ").expect_err("Should overflow");
+    assert_eq!(buf, "rロi");
+    buf.replace_range(1..4, "-rr").expect("Equal replacement fits");
+    assert_eq!(buf, "r-rri");
+    buf.replace_range(1..2, "ロ").expect_err("Should overflow");
+    buf.replace_range(1..3, "ロ").expect_err("Should overflow");
+    buf.replace_range(1..4, "ロ").expect("Fits exactly");
+    assert_eq!(buf, "rロi");
+}
+
+#[test]
+fn should_split_off() {
+    let mut buf = SmolStr::from_str("rロi");
+    let tail = buf.split_off(1);
+    assert_eq!(buf, "r");
+    assert_eq!(tail, "ロi");
+    assert_eq!(tail.len(), 4);
+
+    let mut buf = SmolStr::from_str("rロi");
+    let tail = buf.split_off(0);
+    assert_eq!(buf, "");
+    assert_eq!(tail, "rロi");
+
+    let mut buf = SmolStr::from_str("rロi");
+    let tail = buf.split_off(buf.len());
+    assert_eq!(buf, "rロi");
+    assert_eq!(tail, "");
+}
+
+#[test]
+fn should_split_off_medium() {
+    let mut buf = MediumStr::from_str("rロi");
+    let tail = buf.split_off(4);
+    assert_eq!(buf, "rロ");
+    assert_eq!(tail, "i");
+    assert_eq!(buf.len() + tail.len(), "rロi".len());
+
+    let mut buf = MediumStr::from_str("rロi");
+    assert_eq!(buf.split_off(0), "rロi");
+    assert_eq!(buf, "");
+    let mut buf = MediumStr::from_str("rロi");
+    assert_eq!(buf.split_off(buf.len()), "");
+    assert_eq!(buf, "rロi");
+}
+
+#[test]
+fn should_split_off_big() {
+    let mut buf = BigStr::from_str("rロi");
+    let tail = buf.split_off(4);
+    assert_eq!(buf, "rロ");
+    assert_eq!(tail, "i");
+    assert_eq!(buf.len() + tail.len(), "rロi".len());
+
+    let mut buf = BigStr::from_str("rロi");
+    assert_eq!(buf.split_off(0), "rロi");
+    assert_eq!(buf, "");
+    let mut buf = BigStr::from_str("rロi");
+    assert_eq!(buf.split_off(buf.len()), "");
+    assert_eq!(buf, "rロi");
+}
+
+#[test]
+fn should_truncate_str_by_char_boundary() {
+    let mut buf = SmolStr::from_str("rロi");
+    buf.truncate_str(10);
+    assert_eq!(buf, "rロi");
+    buf.truncate_str(buf.len());
+    assert_eq!(buf, "rロi");
+    buf.truncate_str(3);
+    assert_eq!(buf, "r");
+    assert!(core::str::from_utf8(buf.as_slice()).is_ok());
+
+    let mut buf = MediumStr::from_str("rロi");
+    buf.truncate_str(2);
+    assert_eq!(buf, "r");
+    buf.truncate_str(0);
+    assert_eq!(buf, "");
+
+    let mut buf = BigStr::from_str("rロi");
+    buf.truncate_str(4);
+    assert_eq!(buf, "rロ");
+    buf.truncate_str(3);
+    assert_eq!(buf, "r");
+}
+
+#[test]
+fn should_truncate_front() {
+    let mut buf = SmolStr::from_str("rロi");
+    buf.truncate_front(0);
+    assert_eq!(buf, "rロi");
+    buf.truncate_front(1);
+    assert_eq!(buf, "ロi");
+    buf.truncate_front(2);
+    assert_eq!(buf, "ロi");
+    buf.truncate_front(3);
+    assert_eq!(buf, "i");
+    buf.truncate_front(3);
+    assert_eq!(buf, "");
+
+    let mut buf = MediumStr::from_str("rロi");
+    buf.truncate_front(3);
+    assert_eq!(buf, "ロi");
+    buf.truncate_front(buf.len());
+    assert_eq!(buf, "");
+
+    let mut buf = BigStr::from_str("rロi");
+    buf.truncate_front(4);
+    assert_eq!(buf, "i");
+}
+
+#[test]
+fn should_pop_front_and_back() {
+    let mut buf = MediumStr::from_str("rロiリé");
+    assert_eq!(buf.pop_front(), Some('r'));
+    assert_eq!(buf, "ロiリé");
+    assert_eq!(buf.pop(), Some('é'));
+    assert_eq!(buf, "ロiリ");
+    assert_eq!(buf.pop_front(), Some('ロ'));
+    assert_eq!(buf, "iリ");
+    assert_eq!(buf.pop(), Some('リ'));
+    assert_eq!(buf, "i");
+    assert_eq!(buf.pop_front(), Some('i'));
+    assert_eq!(buf, "");
+    assert!(buf.pop_front().is_none());
+    assert!(buf.pop().is_none());
+
+    let mut buf = SmolStr::from_str("ロri");
+    assert_eq!(buf.pop_front(), Some('ロ'));
+    assert_eq!(buf.pop(), Some('i'));
+    assert_eq!(buf.pop_front(), Some('r'));
+    assert!(buf.pop_front().is_none());
+
+    let mut buf = BigStr::from_str("ロri");
+    assert_eq!(buf.pop_front(), Some('ロ'));
+    assert_eq!(buf, "ri");
+    assert_eq!(buf.pop(), Some('i'));
+    assert_eq!(buf.pop_front(), Some('r'));
+    assert!(buf.pop_front().is_none());
+}
+
+#[test]
+fn should_strip_prefix_and_suffix() {
+    let mut buf = MediumStr::from_str("AT+ロリ\r\n");
+    assert!(!buf.make_strip_prefix("AT-"));
+    assert!(buf.make_strip_prefix("AT+"));
+    assert_eq!(buf, "ロリ\r\n");
+    assert!(!buf.make_strip_suffix("\n\r"));
+    assert!(buf.make_strip_suffix("\r\n"));
+    assert_eq!(buf, "ロリ");
+    assert!(buf.make_strip_prefix("ロ"));
+    assert_eq!(buf, "リ");
+    assert!(buf.make_strip_suffix("リ"));
+    assert_eq!(buf, "");
+    assert!(!buf.make_strip_prefix(""));
+    assert!(!buf.make_strip_suffix(""));
+
+    let mut buf = SmolStr::from_str("ロri");
+    assert!(!buf.make_strip_prefix("ロri."));
+    assert!(buf.make_strip_prefix("ロri"));
+    assert_eq!(buf, "");
+
+    let mut buf = BigStr::from_str("ロri");
+    assert!(!buf.make_strip_prefix(""));
+    assert!(!buf.make_strip_suffix(""));
+    assert_eq!(buf, "ロri");
+    assert!(buf.make_strip_suffix("ロri"));
+    assert_eq!(buf, "");
+}
+
+#[test]
+fn should_make_replace() {
+    let cases: &[(&str, &str, &str)] = &[
+        ("rロiロ", "ロ", "-"),
+        ("rロiロ", "ロ", "リ"),
+        ("rロiロ", "ロ", "<😀>"),
+        ("ロロロ", "ロ", ""),
+        ("aaaa", "aa", "b"),
+        ("aaa", "aa", "bbb"),
+        ("abc", "", "-"),
+        ("", "", "ロ"),
+        ("abc", "d", "ロ"),
+        ("abc", "abc", "ロリ"),
+    ];
+
+    for (input, from, to) in cases {
+        let expected = input.replace(from, to);
+        let mut buf = MediumStr::from_str(input);
+        let count = buf.make_replace(from, to).expect("Should fit");
+        assert_eq!(buf, expected.as_str(), "'{}'.replace('{}', '{}')", input, from, to);
+        assert_eq!(count, input.matches(from).count());
+
+        let mut buf = BigStr::from_str(input);
+        buf.make_replace(from, to).expect("Should fit");
+        assert_eq!(buf, expected.as_str());
+    }
+}
+
+#[test]
+fn should_not_make_replace_on_overflow() {
+    let mut buf = SmolStr::from_str("r-i-");
+    buf.make_replace("-", "ロ").expect_err("Should overflow");
+    assert_eq!(buf, "r-i-");
+    assert_eq!(buf.make_replace("-", "").expect("Should fit"), 2);
+    assert_eq!(buf, "ri");
+    assert_eq!(buf.make_replace("i", "ロ").expect("Fits exactly"), 1);
+    assert_eq!(buf, "rロ");
+    assert_eq!(buf.remaining(), 1);
+}
+
+#[test]
+fn should_not_make_replace_on_overflowing_size() {
+    //65536 * 67000 wraps around on 32-bit targets
+    let mut buf = BigStr::new();
+    buf.extend_with('a', BigStr::capacity());
+    let to = "b".repeat(65536);
+    assert!(matches!(buf.make_replace("a", &to), Err(str_buf::StrBufError::Overflow)));
+    assert_eq!(buf.len(), BigStr::capacity());
+    assert!(buf.chars().all(|ch| ch == 'a'));
+}
+
+#[test]
+fn should_replace_char() {
+    let mut buf = MediumStr::from_str("ロrロiロ");
+    assert_eq!(buf.replace_char('ロ', '-').expect("Should fit"), 3);
+    assert_eq!(buf, "-r-i-");
+    assert_eq!(buf.replace_char('-', 'ロ').expect("Should fit"), 3);
+    assert_eq!(buf, "ロrロiロ");
+    assert_eq!(buf.replace_char('ロ', 'リ').expect("Should fit"), 3);
+    assert_eq!(buf, "リrリiリ");
+    assert_eq!(buf.replace_char('x', 'リ').expect("Nothing to replace"), 0);
+    assert_eq!(buf, "リrリiリ");
+
+    let mut buf = BigStr::from_str("ロrロiロ");
+    assert_eq!(buf.replace_char('ロ', '-').expect("Should fit"), 3);
+    assert_eq!(buf, "-r-i-");
+}
+
+#[test]
+fn should_not_replace_char_on_overflow() {
+    let mut buf = SmolStr::from_str("r-i");
+    buf.replace_char('-', '😀').expect_err("Should overflow");
+    assert_eq!(buf, "r-i");
+    assert_eq!(buf.replace_char('r', 'ロ').expect("Fits exactly"), 1);
+    assert_eq!(buf, "ロ-i");
+}
+
+#[test]
+fn should_remove_matches() {
+    let mut buf = MediumStr::from_str("ロrロロiロ");
+    buf.remove_matches("ロ");
+    assert_eq!(buf, "ri");
+
+    let mut buf = MediumStr::from_str("abababa");
+    buf.remove_matches("aba");
+    assert_eq!(buf, "b");
+    buf.remove_matches("");
+    assert_eq!(buf, "b");
+    buf.remove_matches("b");
+    assert_eq!(buf, "");
+
+    let mut buf = BigStr::from_str("--r--i--");
+    buf.remove_matches("--");
+    assert_eq!(buf, "ri");
+
+    let mut buf = SmolStr::from_str("ロri");
+    buf.remove_matches("x");
+    assert_eq!(buf, "ロri");
+}
+
+#[test]
+fn should_make_reverse() {
+    let cases = ["", "r", "abc", "ロリ", "abcロ", "rロiリé😀"];
+
+    for input in cases {
+        let expected = input.chars().rev().collect::<String>();
+
+        let mut buf = MediumStr::from_str(input);
+        buf.make_reverse();
+        assert_eq!(buf, expected.as_str());
+        buf.make_reverse();
+        assert_eq!(buf, input);
+
+        let mut buf = BigStr::from_str(input);
+        buf.make_reverse();
+        assert_eq!(buf, expected.as_str());
+    }
+
+    let mut buf = SmolStr::from_str("abロ");
+    buf.make_reverse();
+    assert_eq!(buf, "ロba");
+}
+
+#[test]
+fn should_truncate_with_ellipsis() {
+    let cases = [
+        ("", 3, "...", ""),
+        ("short", 5, "…", "short"),
+        ("device name", 8, "...", "devic..."),
+        ("device name", 8, "…", "device …"),
+        ("ロリロリロリ", 4, "…", "ロリロ…"),
+        ("ロリロリロリ", 4, "...", "ロ..."),
+        ("abcdef", 5, "…", "abc…"),
+        ("ab", 1, "…", "a"),
+        ("abcdef", 2, "...", "ab"),
+        ("abcdef", 0, "", ""),
+    ];
+
+    for (input, max_chars, ellipsis, expected) in cases {
+        let mut buf = MediumStr::from_str(input);
+        buf.truncate_with_ellipsis(max_chars, ellipsis);
+        assert_eq!(buf, expected, "'{}' to {} chars with '{}'", input, max_chars, ellipsis);
+        assert!(buf.len() <= input.len());
+        assert!(buf.chars().count() <= max_chars || input.chars().count() <= max_chars);
+
+        let mut buf = BigStr::from_str(input);
+        buf.truncate_with_ellipsis(max_chars, ellipsis);
+        assert_eq!(buf, expected);
+    }
+}
+
+#[test]
+fn should_copy_substr() {
+    let buf = MediumStr::from_str("https://ロリ.jp/path");
+    let host: StrBuf<10> = buf.substr(8..17).expect("Should fit");
+    assert_eq!(host, "ロリ.jp");
+    let all: StrBuf<30> = buf.substr(..).expect("Should fit");
+    assert_eq!(all, buf.as_str());
+    let empty: StrBuf<0> = buf.substr(5..5).expect("Should fit");
+    assert_eq!(empty, "");
+    buf.substr::<5, _>(8..17).expect_err("Should overflow");
+
+    assert_eq!(buf.substr_same(..5), "https");
+    assert_eq!(buf.substr_same(17..), "/path");
+    assert_eq!(buf.substr_same(0..0), "");
+
+    let buf = BigStr::from_str("rロi");
+    assert_eq!(buf.substr_same(1..=3), "ロ");
+}
+
+#[test]
+fn should_split_to_pair() {
+    const ORIGINAL: MediumStr = MediumStr::from_str("key=ロリ");
+    const PAIR: (MediumStr, MediumStr) = ORIGINAL.split_to_pair(4);
+    assert_eq!(PAIR.0, "key=");
+    assert_eq!(PAIR.1, "ロリ");
+    assert_eq!(ORIGINAL, "key=ロリ");
+
+    let (left, right) = ORIGINAL.split_to_pair(0);
+    assert_eq!(left, "");
+    assert_eq!(right, "key=ロリ");
+    let (left, right) = ORIGINAL.split_to_pair(ORIGINAL.len());
+    assert_eq!(left, "key=ロリ");
+    assert_eq!(right, "");
+
+    let buf = SmolStr::from_str("rロi");
+    let (left, right) = buf.split_to_pair(4);
+    assert_eq!(left, "rロ");
+    assert_eq!(right, "i");
+
+    let buf = BigStr::from_str("rロi");
+    let (left, right) = buf.split_to_pair(1);
+    assert_eq!(left, "r");
+    assert_eq!(right, "ロi");
+}
+
+#[test]
+fn should_split_collect() {
+    let buf = MediumStr::from_str("baud=9600,ロリ,,8N1,");
+    let (parts, count) = buf.split_collect::<10, 8>(',').expect("Should fit");
+    assert_eq!(count, 5);
+    assert_eq!(parts[0], "baud=9600");
+    assert_eq!(parts[1], "ロリ");
+    assert_eq!(parts[2], "");
+    assert_eq!(parts[3], "8N1");
+    assert_eq!(parts[4], "");
+    assert_eq!(parts[5], "");
+
+    match buf.split_collect::<5, 8>(',') {
+        Err(str_buf::StrBufError::Overflow) => (),
+        _ => panic!("Should overflow field"),
+    }
+    match buf.split_collect::<10, 4>(',') {
+        Err(str_buf::StrBufError::TooManyParts) => (),
+        _ => panic!("Should exceed number of fields"),
+    }
+
+    let (parts, count) = buf.split_collect::<10, 5>(',').expect("Fits exactly");
+    assert_eq!(count, 5);
+    assert_eq!(parts[3], "8N1");
+
+    let (parts, count) = MediumStr::new().split_collect::<1, 1>(',').expect("Should fit");
+    assert_eq!(count, 1);
+    assert_eq!(parts[0], "");
+}
+
+#[test]
+fn should_split_collect_by_str() {
+    let buf = BigStr::from_str("a::ロ::::b");
+    let (parts, count) = buf.split_collect_str::<4, 4>("::").expect("Should fit");
+    assert_eq!(count, 4);
+    assert_eq!(parts[0], "a");
+    assert_eq!(parts[1], "ロ");
+    assert_eq!(parts[2], "");
+    assert_eq!(parts[3], "b");
+
+    let buf = MediumStr::from_str("ロ→リ→");
+    let (parts, count) = buf.split_collect_str::<4, 3>("→").expect("Should fit");
+    assert_eq!(count, 3);
+    assert_eq!(parts[0], "ロ");
+    assert_eq!(parts[1], "リ");
+    assert_eq!(parts[2], "");
+}
+
+#[test]
+fn should_trim_in_const() {
+    const TEXT: MediumStr = MediumStr::from_str(" \t config\u{3000}\r\n").into_trimmed();
+    assert_eq!(TEXT, "config");
+
+    let inputs = ["", " ", "\u{A0}\u{2003}", " a ", "\u{2028}ロ リ\u{85}", "abc", "\n\na\tb \u{200B}\n"];
+    for input in inputs.iter() {
+        let mut expected = MediumStr::from_str(input);
+        expected.make_trim();
+        assert_eq!(MediumStr::from_str(input).into_trimmed(), expected);
+        assert_eq!(MediumStr::from_str(input).into_trim_left(), input.trim_start());
+        assert_eq!(MediumStr::from_str(input).into_trim_right(), input.trim_end());
+    }
+
+    assert_eq!(SmolStr::from_str(" ロ ").into_trim_left(), "ロ ");
+    assert_eq!(BigStr::from_str(" ロ ").into_trim_right(), " ロ");
+}
+
+#[test]
+fn should_strip_affix_in_const() {
+    const BASE: MediumStr = MediumStr::from_str("ロbase.key");
+    const KEY: Option<MediumStr> = BASE.into_strip_prefix("ロbase.");
+    const BASE_ONLY: Option<MediumStr> = BASE.into_strip_suffix(".key");
+
+    assert_eq!(KEY.expect("Should strip prefix"), "key");
+    assert_eq!(BASE_ONLY.expect("Should strip suffix"), "ロbase");
+    assert!(BASE.into_strip_prefix("base").is_none());
+    assert!(BASE.into_strip_suffix("base").is_none());
+    assert_eq!(BASE.into_strip_prefix("").expect("Empty prefix"), BASE);
+    assert_eq!(BASE.into_strip_suffix("").expect("Empty suffix"), BASE);
+
+    let text = SmolStr::from_str("aロ");
+    assert!(text.into_strip_suffix("aaロ").is_none());
+    assert_eq!(text.into_strip_suffix("aロ").expect("Should strip"), "");
+    assert_eq!(BigStr::from_str("リロリ").into_strip_prefix("リ").expect("Should strip"), "ロリ");
+}
+
+#[test]
+fn should_split_at_in_const() {
+    const PAIR: MediumStr = MediumStr::from_str("key=ロ");
+    const SPLIT: (MediumStr, MediumStr) = match PAIR.const_find("=") {
+        Some(at) => PAIR.const_split_at(at),
+        None => panic!("No separator"),
+    };
+    assert_eq!(SPLIT.0, "key");
+    assert_eq!(SPLIT.1, "=ロ");
+    assert_eq!(SPLIT.0.len(), 3);
+    assert_eq!(SPLIT.1.len(), 4);
+
+    let (left, right) = PAIR.const_split_at(0);
+    assert_eq!(left, "");
+    assert_eq!(right, "key=ロ");
+    assert_eq!(right.len(), PAIR.len());
+
+    let (left, right) = PAIR.const_split_at(PAIR.len());
+    assert_eq!(left, "key=ロ");
+    assert_eq!(right, "");
+    assert_eq!(left.len(), PAIR.len());
+
+    let (left, right) = SmolStr::from_str("aロ").const_split_at(1);
+    assert_eq!(left, "a");
+    assert_eq!(right, "ロ");
+}
+
+#[test]
+fn should_modify_through_mut_str() {
+    let mut text = MediumStr::from_str("HELLO world");
+    text[..3].make_ascii_lowercase();
+    assert_eq!(text, "helLO world");
+    text.as_mut_str()[6..].make_ascii_uppercase();
+    assert_eq!(text, "helLO WORLD");
+
+    let (left, right) = text.split_at_mut(5);
+    left.make_ascii_uppercase();
+    right.make_ascii_lowercase();
+    assert_eq!(text, "HELLO world");
+
+    let mut text = SmolStr::from_str("aロ");
+    if let Some(head) = text.get_mut(..1) {
+        head.make_ascii_uppercase();
+    }
+    assert_eq!(text, "Aロ");
+    assert!(text.get_mut(..2).is_none());
+    assert_eq!(BigStr::new().as_mut_str(), "");
+}
@@ -1,5 +1,6 @@
 
 type SmolStr = str_buf::StrBuf<5>;
+type MediumStr = str_buf::StrBuf<290>;
 
 #[test]
 fn should_get_byte() {
@@ -14,3 +15,174 @@ fn should_get_byte() {
     assert_eq!(BYTE3, Some(b'3'));
     assert_eq!(OUT_OF_BOUNDS_BYTE, None);
 }
+
+#[test]
+fn should_find_byte() {
+    const TEST: SmolStr = SmolStr::from_str("a:b:");
+    const FIRST: Option<usize> = TEST.find_byte(b':');
+    const LAST: Option<usize> = TEST.rfind_byte(b':');
+
+    assert_eq!(FIRST, Some(1));
+    assert_eq!(LAST, Some(3));
+    assert_eq!(TEST.find_byte(b'a'), Some(0));
+    assert_eq!(TEST.rfind_byte(b'a'), Some(0));
+    assert_eq!(TEST.find_byte(b'c'), None);
+    assert_eq!(TEST.rfind_byte(b'c'), None);
+    assert_eq!(SmolStr::new().find_byte(b':'), None);
+    assert_eq!(SmolStr::new().rfind_byte(b':'), None);
+}
+
+#[test]
+fn should_count_chars() {
+    const TEST: MediumStr = MediumStr::from_str("aßロ😀z");
+    const COUNT: usize = TEST.char_count();
+
+    assert_eq!(COUNT, 5);
+    assert_eq!(COUNT, TEST.chars().count());
+    assert_eq!(SmolStr::new().char_count(), 0);
+    assert_eq!(SmolStr::from_str("abcd").char_count(), 4);
+    assert_eq!(SmolStr::from_str("ロ").char_count(), 1);
+}
+
+#[test]
+fn should_get_first_and_last_char() {
+    const TEST: MediumStr = MediumStr::from_str("😀aßロ");
+    const FIRST: Option<char> = TEST.first_char();
+    const LAST: Option<char> = TEST.last_char();
+
+    assert_eq!(FIRST, Some('😀'));
+    assert_eq!(LAST, Some('ロ'));
+
+    let inputs = ["", "a", "ß", "ロ", "😀", "aß", "ßロ", "ロ😀", "😀a"];
+    for input in inputs.iter() {
+        let text = MediumStr::from_str(input);
+        assert_eq!(text.first_char(), input.chars().next());
+        assert_eq!(text.last_char(), input.chars().next_back());
+    }
+    assert_eq!(SmolStr::new().first_char(), None);
+    assert_eq!(SmolStr::new().last_char(), None);
+}
+
+#[test]
+fn should_check_empty_and_full() {
+    const TEST: SmolStr = SmolStr::from_str("abcd");
+    const _: () = assert!(TEST.is_full());
+    const _: () = assert!(!TEST.is_empty());
+    const _: () = assert!(TEST.spare_capacity() == 0);
+
+    fn check<const N: usize>() {
+        let mut text = str_buf::StrBuf::<N>::new();
+        assert!(text.is_empty());
+        assert_eq!(text.is_full(), N == 0);
+        assert_eq!(text.spare_capacity(), text.remaining());
+        assert_eq!(text.spare_capacity(), str_buf::StrBuf::<N>::capacity());
+
+        if N > 0 {
+            text.push_str("a");
+            assert!(!text.is_empty());
+            assert_eq!(text.spare_capacity(), str_buf::StrBuf::<N>::capacity() - 1);
+            while text.push_str("a") > 0 {}
+            assert!(text.is_full());
+            assert_eq!(text.spare_capacity(), 0);
+        }
+    }
+
+    check::<0>();
+    check::<5>();
+    check::<290>();
+    check::<67_000>();
+}
+
+#[test]
+fn should_get_nth_char() {
+    const TEST: MediumStr = MediumStr::from_str("aßロ😀z");
+    const THIRD: Option<char> = TEST.get_char(2);
+
+    assert_eq!(THIRD, Some('ロ'));
+    for idx in 0..7 {
+        assert_eq!(TEST.get_char(idx), TEST.chars().nth(idx));
+    }
+    assert_eq!(SmolStr::new().get_char(0), None);
+    assert_eq!(SmolStr::from_str("ab").get_char(usize::MAX), None);
+}
+
+#[test]
+fn should_get_char_at_byte_offset() {
+    let text = MediumStr::from_str("aロ😀");
+
+    assert_eq!(text.char_at(0), Some('a'));
+    assert_eq!(text.char_at(1), Some('ロ'));
+    assert_eq!(text.char_at(2), None);
+    assert_eq!(text.char_at(3), None);
+    assert_eq!(text.char_at(4), Some('😀'));
+    assert_eq!(text.char_at(6), None);
+    assert_eq!(text.char_at(8), None);
+    assert_eq!(text.char_at(100), None);
+
+    let floors = [0, 1, 1, 1, 4, 4, 4, 4, 8, 8];
+    let ceils = [0, 1, 4, 4, 4, 8, 8, 8, 8, 8];
+    for idx in 0..floors.len() {
+        assert_eq!(text.floor_char_boundary(idx), floors[idx], "floor of {}", idx);
+        assert_eq!(text.ceil_char_boundary(idx), ceils[idx], "ceil of {}", idx);
+        assert!(text.is_char_boundary(text.floor_char_boundary(idx)));
+        assert!(text.is_char_boundary(text.ceil_char_boundary(idx)));
+    }
+
+    assert_eq!(SmolStr::new().char_at(0), None);
+    assert_eq!(SmolStr::new().floor_char_boundary(3), 0);
+    assert_eq!(SmolStr::new().ceil_char_boundary(3), 0);
+}
+
+#[test]
+fn should_convert_char_and_byte_indexes() {
+    let text = MediumStr::from_str("aロ😀bß");
+
+    assert_eq!(text.char_range_to_byte_range(1..3), Some(1..8));
+    assert_eq!(text.char_range_to_byte_range(0..0), Some(0..0));
+    assert_eq!(text.char_range_to_byte_range(5..5), Some(11..11));
+    assert_eq!(text.char_range_to_byte_range(3..5), Some(8..11));
+    assert_eq!(text.char_range_to_byte_range(3..6), None);
+    assert_eq!(text.char_range_to_byte_range(6..6), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = text.char_range_to_byte_range(3..2);
+    assert_eq!(reversed, None);
+    assert_eq!(&text[text.char_range_to_byte_range(1..4).unwrap()], "ロ😀b");
+
+    assert_eq!(text.byte_to_char_index(0), Some(0));
+    assert_eq!(text.byte_to_char_index(2), None);
+    assert_eq!(text.byte_to_char_index(4), Some(2));
+    assert_eq!(text.byte_to_char_index(11), Some(5));
+    assert_eq!(text.byte_to_char_index(12), None);
+
+    let count = text.chars().count();
+    for start in 0..=count {
+        for end in start..=count {
+            let range = text.char_range_to_byte_range(start..end).expect("Should be in range");
+            assert_eq!(text.byte_to_char_index(range.start), Some(start));
+            assert_eq!(text.byte_to_char_index(range.end), Some(end));
+            assert_eq!(text[range].chars().count(), end - start);
+        }
+    }
+
+    assert_eq!(SmolStr::new().char_range_to_byte_range(0..0), Some(0..0));
+    assert_eq!(SmolStr::new().byte_to_char_index(0), Some(0));
+}
+
+#[test]
+fn should_get_sub_str() {
+    const TEST: MediumStr = MediumStr::from_str("key=ロ");
+    const KEY: Option<&str> = match TEST.find_byte(b'=') {
+        Some(idx) => TEST.get_str(0, idx),
+        None => None,
+    };
+    assert_eq!(KEY, Some("key"));
+
+    let len = TEST.len();
+    for start in 0..=len + 1 {
+        for end in 0..=len + 1 {
+            assert_eq!(TEST.get_str(start, end), TEST.as_str().get(start..end), "{}..{}", start, end);
+        }
+    }
+    assert_eq!(SmolStr::new().get_str(0, 0), Some(""));
+    assert_eq!(SmolStr::new().get_str(0, 1), None);
+}
@@ -0,0 +1,82 @@
+use str_buf::StrBuf;
+
+type MediumStr = StrBuf<290>;
+
+#[test]
+fn should_wrap_chunks() {
+    let cases: &[(&str, usize, &[&str])] = &[
+        ("", 5, &[]),
+        ("short", 21, &["short"]),
+        ("hello world", 8, &["hello ", "world"]),
+        ("hello world", 5, &["hello", " ", "world"]),
+        ("ab cd ef", 5, &["ab ", "cd ef"]),
+        ("averyveryverylongword", 8, &["averyver", "yverylon", "gword"]),
+        ("trailing   ", 9, &["trailing ", "  "]),
+        ("ロリロリ", 7, &["ロリ", "ロリ"]),
+        ("aロリ", 5, &["aロ", "リ"]),
+        ("ロ", 2, &["ロ"]),
+        ("ロ リ", 6, &["ロ ", "リ"]),
+    ];
+
+    for (input, max_bytes, expected) in cases {
+        let buf = MediumStr::from_str(input);
+        let chunks = buf.wrap_chunks(*max_bytes).collect::<Vec<_>>();
+        assert_eq!(&chunks, expected, "'{}' wrapped at {}", input, max_bytes);
+        assert_eq!(chunks.concat(), *input);
+    }
+}
+
+#[test]
+fn should_iterate_owned_chars() {
+    fn chars() -> impl DoubleEndedIterator<Item = char> {
+        MediumStr::from_str("aßロ😀z").into_iter()
+    }
+
+    assert!(chars().eq("aßロ😀z".chars()));
+    assert!(chars().rev().eq("aßロ😀z".chars().rev()));
+
+    let mut iter = MediumStr::from_str("aßロ😀z").into_iter();
+    assert_eq!(iter.next(), Some('a'));
+    assert_eq!(iter.next_back(), Some('z'));
+    assert_eq!(iter.as_str(), "ßロ😀");
+    assert_eq!(iter.next_back(), Some('😀'));
+    assert_eq!(iter.next(), Some('ß'));
+    assert_eq!(iter.next(), Some('ロ'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let text = MediumStr::from_str("ロリ");
+    let mut collected = Vec::new();
+    for ch in &text {
+        collected.push(ch);
+    }
+    assert_eq!(collected, ['ロ', 'リ']);
+    assert_eq!(MediumStr::new().into_iter().next(), None);
+}
+
+#[test]
+fn should_iterate_owned_bytes() {
+    let text = MediumStr::from_str("aロ");
+    let mut queue = [0u8; 8];
+    let mut len = 0;
+    let iter = text.into_bytes_iter();
+    assert_eq!(iter.len(), 4);
+    for byte in iter {
+        queue[len] = byte;
+        len += 1;
+    }
+    assert_eq!(&queue[..len], "aロ".as_bytes());
+
+    let mut iter = text.into_bytes_iter();
+    assert_eq!(iter.next_back(), Some(0xAD));
+    assert_eq!(iter.next(), Some(b'a'));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.as_slice(), &[0xE3, 0x83]);
+    assert!(iter.rev().eq([0x83, 0xE3].iter().copied()));
+
+    let mut iter = MediumStr::new().into_bytes_iter();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(StrBuf::<0>::new().into_bytes_iter().count(), 0);
+}
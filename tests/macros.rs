@@ -0,0 +1,64 @@
+use str_buf::{str_buf, StrBuf};
+
+#[test]
+fn should_create_from_literal() {
+    const TEXT: StrBuf<4> = str_buf!("ロ");
+    assert_eq!(TEXT, "ロ");
+    assert_eq!(TEXT.remaining(), 0);
+
+    const EMPTY: StrBuf<0> = str_buf!("");
+    assert_eq!(EMPTY, "");
+
+    const NAME: &str = "name";
+    let text = str_buf!(NAME);
+    assert_eq!(text, "name");
+    assert_eq!(core::mem::size_of_val(&text), 5);
+
+    let text = str_buf!(300; "ロリ");
+    assert_eq!(text, "ロリ");
+    assert_eq!(text.remaining(), 294);
+}
+
+struct Packet {
+    name: str_buf::with_capacity!(16),
+}
+
+type Name = str_buf::with_capacity!(16);
+str_buf::assert_capacity!(Name, 16);
+str_buf::assert_capacity!(str_buf::with_capacity!(0), 0);
+str_buf::assert_capacity!(str_buf::with_capacity!(255), 255);
+str_buf::assert_capacity!(str_buf::with_capacity!(256), 256);
+str_buf::assert_capacity!(str_buf::with_capacity!(65535), 65535);
+str_buf::assert_capacity!(str_buf::with_capacity!(65536), 65536);
+
+#[test]
+fn should_declare_with_capacity() {
+    let mut packet = Packet {
+        name: Name::new(),
+    };
+    assert_eq!(packet.name.push_str("0123456789abcdefg"), 16);
+    assert_eq!(packet.name, "0123456789abcdef");
+    assert_eq!(packet.name.remaining(), 0);
+}
+
+#[test]
+fn should_concat_const_parts() {
+    const SEP: &str = "ロ";
+    const TEXT: StrBuf<8> = str_buf::concat!("ab", SEP, "cd",);
+    assert_eq!(TEXT, "abロcd");
+    assert_eq!(TEXT.remaining(), 0);
+
+    let text = str_buf::concat!("single");
+    assert_eq!(text, "single");
+    assert_eq!(core::mem::size_of_val(&text), 7);
+}
+
+#[test]
+fn should_embed_env_variable() {
+    const VERSION: str_buf::with_capacity!(32) = str_buf::const_env!(32; "CARGO_PKG_VERSION").and("-dev");
+    assert_eq!(VERSION, concat!(env!("CARGO_PKG_VERSION"), "-dev"));
+
+    let name = str_buf::const_env!("CARGO_PKG_NAME");
+    assert_eq!(name, "str-buf");
+    assert_eq!(name.remaining(), 0);
+}
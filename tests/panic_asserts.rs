@@ -15,3 +15,180 @@ fn from_str_overflow_panic() {
 fn and_overflow_panic() {
     let _ = SmolStr::from_str("lolka").and("extra");
 }
+
+#[test]
+#[should_panic]
+fn insert_str_not_char_boundary_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.insert_str(1, "r");
+}
+
+#[test]
+#[should_panic]
+fn insert_str_out_of_bounds_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.insert_str(4, "r");
+}
+
+#[test]
+#[should_panic]
+fn insert_char_inside_multibyte_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.insert(2, 'r');
+}
+
+#[test]
+#[should_panic]
+fn remove_at_end_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.remove(buf.len());
+}
+
+#[test]
+#[should_panic]
+fn remove_not_char_boundary_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.remove(1);
+}
+
+#[test]
+#[should_panic]
+fn replace_range_not_char_boundary_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.replace_range(0..2, "r");
+}
+
+#[test]
+#[should_panic]
+fn replace_range_out_of_bounds_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.replace_range(0..4, "r");
+}
+
+#[test]
+#[should_panic]
+fn split_off_not_char_boundary_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.split_off(1);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_within_not_char_boundary_panic() {
+    let mut buf = SmolStr::from_str("ロ");
+    let _ = buf.extend_from_within(1..);
+}
+
+#[test]
+#[should_panic]
+fn substr_not_char_boundary_panic() {
+    let buf = SmolStr::from_str("rロ");
+    let _ = buf.substr::<5, _>(0..2);
+}
+
+#[test]
+#[should_panic]
+fn substr_same_out_of_bounds_panic() {
+    let buf = SmolStr::from_str("rロ");
+    let _ = buf.substr_same(0..5);
+}
+
+#[test]
+#[should_panic]
+fn split_to_pair_not_char_boundary_panic() {
+    let buf = SmolStr::from_str("rロ");
+    let _ = buf.split_to_pair(2);
+}
+
+#[test]
+#[should_panic]
+fn from_ascii_non_ascii_panic() {
+    let _ = SmolStr::from_ascii("ロ");
+}
+
+#[test]
+#[should_panic]
+fn from_char_overflow_panic() {
+    let _ = ZeroStr::from_char('a');
+}
+
+#[test]
+#[should_panic]
+fn from_str_repeat_overflow_panic() {
+    let _ = SmolStr::from_str_repeat("ab", 3);
+}
+
+#[test]
+#[should_panic]
+fn from_utf8_unwrap_invalid_panic() {
+    let _ = SmolStr::from_utf8_unwrap(b"\xED\xA0\x80");
+}
+
+#[test]
+#[should_panic]
+fn const_split_at_not_char_boundary_panic() {
+    let _ = SmolStr::from_str("ロ").const_split_at(1);
+}
+
+#[test]
+#[should_panic]
+fn and_dec_overflow_panic() {
+    let _ = SmolStr::new().and_dec(12345);
+}
+
+#[test]
+#[should_panic]
+fn and_char_overflow_panic() {
+    let _ = SmolStr::from_str("ab").and_char('ロ');
+}
+
+#[test]
+#[should_panic]
+fn and_buf_overflow_panic() {
+    let _ = SmolStr::from_str("ab").and_buf(&SmolStr::from_str("cde"));
+}
+
+#[test]
+#[should_panic]
+fn concat_overflow_panic() {
+    let _ = SmolStr::concat(&SmolStr::from_str("ab"), &SmolStr::from_str("cde"));
+}
+
+#[test]
+#[should_panic]
+fn resize_overflow_panic() {
+    let _ = SmolStr::from_str("abcd").resize::<4>();
+}
+
+#[test]
+#[should_panic]
+fn to_array_overflow_panic() {
+    let _ = SmolStr::from_str("abc").to_array::<2>();
+}
+
+#[test]
+#[should_panic]
+fn add_overflow_panic() {
+    let _ = SmolStr::from_str("ab") + "cde";
+}
+
+#[test]
+#[should_panic]
+fn add_assign_overflow_panic() {
+    let mut text = SmolStr::from_str("ab");
+    text += 'ロ';
+}
+
+#[test]
+#[should_panic]
+fn reserve_overflow_panic() {
+    let mut text = SmolStr::from_str("ab");
+    text.reserve(3);
+}
+
+#[test]
+#[should_panic]
+fn push_char_overflow_panic() {
+    let mut text = SmolStr::from_str("ab");
+    text.push_char('ロ');
+}
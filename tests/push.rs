@@ -57,6 +57,7 @@ fn should_return_error_on_fmt_write_overflow() {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn should_correctly_convert_ascii_case() {
     let mut buf = SmolStr::new();
     assert_eq!(buf.push_str("ロri"), "ロri".len());
@@ -107,6 +108,7 @@ fn should_correctly_truncate_by_char_boundary() {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn should_correctly_convert_ascii_case_medium() {
     let mut buf = MediumStr::new();
     assert_eq!(buf.push_str("ロri"), "ロri".len());
@@ -166,6 +168,7 @@ fn should_correctly_truncate_by_char_boundary_medium() {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn should_correctly_convert_ascii_case_big() {
     let mut buf = BigStr::new();
     assert_eq!(buf.push_str("ロri"), "ロri".len());
@@ -224,3 +227,793 @@ fn should_correctly_truncate_by_char_boundary_big() {
     let copy = buf;
     assert_eq!(copy, buf);
 }
+
+#[test]
+fn should_push_and_pop_chars() {
+    let mut buf = SmolStr::new();
+
+    buf.push('ロ').expect("Should fit");
+    buf.push('r').expect("Should fit");
+    assert_eq!(buf, "ロr");
+    assert_eq!(buf.remaining(), 1);
+
+    buf.push('リ').expect_err("Should overflow");
+    assert_eq!(buf, "ロr");
+    assert_eq!(buf.len(), "ロr".len());
+
+    buf.push('i').expect("Should fit");
+    assert_eq!(buf, "ロri");
+    buf.push('.').expect_err("Should overflow");
+    assert_eq!(buf.len(), "ロri".len());
+
+    assert_eq!(buf.pop(), Some('i'));
+    assert_eq!(buf.pop(), Some('r'));
+    assert_eq!(buf.pop(), Some('ロ'));
+    assert!(buf.pop().is_none());
+
+    buf.push('ロ').expect("Should fit");
+    assert_eq!(buf.pop(), Some('ロ'));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn should_push_and_pop_chars_medium_and_big() {
+    let mut medium = MediumStr::new();
+    let mut big = BigStr::new();
+
+    for ch in ['ロ', 'r', 'é', '😀'] {
+        medium.push(ch).expect("Should fit");
+        big.push(ch).expect("Should fit");
+    }
+    assert_eq!(medium, "ロré😀");
+    assert_eq!(big, "ロré😀");
+
+    for expected in ['😀', 'é', 'r', 'ロ'] {
+        assert_eq!(medium.pop(), Some(expected));
+        assert_eq!(big.pop(), Some(expected));
+    }
+    assert!(medium.pop().is_none());
+    assert!(big.pop().is_none());
+}
+
+#[test]
+fn should_try_push_str_all_or_nothing() {
+    let mut buf = SmolStr::new();
+
+    buf.try_push_str("ロ").expect("Should fit");
+    buf.try_push_str("ri.").expect_err("Should overflow");
+    assert_eq!(buf, "ロ");
+    assert_eq!(buf.len(), "ロ".len());
+
+    buf.try_push_str("ri").expect("Should fit");
+    assert_eq!(buf, "ロri");
+    buf.try_push_str("").expect("Empty string always fits");
+    buf.try_push_str(".").expect_err("Should overflow");
+    assert_eq!(buf, "ロri");
+}
+
+#[test]
+fn should_trim_matches() {
+    let mut string = MediumStr::from_str("\0\"ロtest\"\0\0");
+    string.make_trim_matches(&['\0', '"']);
+    assert_eq!(string, "ロtest");
+    string.make_trim_matches(&['ロ', 't']);
+    assert_eq!(string, "es");
+    string.make_trim_matches(&['e', 's']);
+    assert_eq!(string, "");
+    string.make_trim_matches(&['e', 's']);
+    assert_eq!(string, "");
+}
+
+#[test]
+fn should_trim_start_and_end_matches() {
+    let mut string = BigStr::from_str("ロリtestリロ");
+    string.make_trim_start_matches(&['ロ', 'リ']);
+    assert_eq!(string, "testリロ");
+    string.make_trim_end_matches(&['ロ', 'リ']);
+    assert_eq!(string, "test");
+    string.make_trim_end_matches(&[]);
+    assert_eq!(string, "test");
+
+    let mut string = SmolStr::from_str("\0\0\0");
+    string.make_trim_end_matches(&['\0']);
+    assert_eq!(string, "");
+    let mut string = SmolStr::from_str("\0\0\0");
+    string.make_trim_start_matches(&['\0']);
+    assert_eq!(string, "");
+}
+
+#[test]
+fn should_pad_to_width() {
+    let mut buf = MediumStr::from_str("ロr");
+    buf.pad_left(4, '-').expect("Should fit");
+    assert_eq!(buf, "--ロr");
+    buf.pad_right(6, 'リ').expect("Should fit");
+    assert_eq!(buf, "--ロrリリ");
+    buf.pad_right(6, 'リ').expect("Already wide enough");
+    buf.pad_left(3, 'リ').expect("Already wider");
+    assert_eq!(buf, "--ロrリリ");
+
+    let mut buf = MediumStr::from_str("ロ");
+    buf.center(4, 'リ').expect("Should fit");
+    assert_eq!(buf, "リロリリ");
+    assert_eq!(buf.as_str(), format!("{:リ^4}", "ロ"));
+
+    let mut buf = BigStr::from_str("ri");
+    buf.center(6, '*').expect("Should fit");
+    assert_eq!(buf, "**ri**");
+}
+
+#[test]
+fn should_not_pad_on_overflow() {
+    let mut buf = SmolStr::from_str("ri");
+    buf.pad_left(4, 'ロ').expect_err("Should overflow");
+    buf.pad_right(4, 'ロ').expect_err("Should overflow");
+    buf.center(4, 'é').expect_err("Should overflow");
+    buf.pad_left(usize::MAX, 'é').expect_err("Should overflow");
+    assert_eq!(buf, "ri");
+    buf.center(5, '-').expect("Fits exactly");
+    assert_eq!(buf, "-ri--");
+}
+
+#[test]
+fn should_push_repeat() {
+    let mut buf = MediumStr::new();
+    assert_eq!(buf.push_repeat("- ", 0), 0);
+    assert_eq!(buf.push_repeat("", 10), 0);
+    assert_eq!(buf, "");
+    assert_eq!(buf.push_repeat("- ", 3), 6);
+    assert_eq!(buf, "- - - ");
+    assert_eq!(buf.push_repeat("ロ", 2), 6);
+    assert_eq!(buf, "- - - ロロ");
+
+    let mut buf = SmolStr::from_str("r");
+    assert_eq!(buf.push_repeat("ロ", 2), 3);
+    assert_eq!(buf, "rロ");
+    assert_eq!(buf.push_repeat("ロ", 1), 0);
+    assert_eq!(buf.push_repeat("i", usize::MAX), 1);
+    assert_eq!(buf, "rロi");
+
+    let mut buf = SmolStr::new();
+    assert_eq!(buf.push_repeat("aロ", 2), 5);
+    assert_eq!(buf, "aロa");
+
+    let mut buf = SmolStr::new();
+    assert_eq!(buf.push_repeat("ロa", 2), 4);
+    assert_eq!(buf, "ロa");
+
+    let mut buf = BigStr::new();
+    assert_eq!(buf.push_repeat("=", BigStr::capacity() + 1), BigStr::capacity());
+    assert_eq!(buf.remaining(), 0);
+    assert!(buf.chars().all(|ch| ch == '='));
+}
+
+#[test]
+fn should_extend_with_char() {
+    let mut buf = SmolStr::from_str("[");
+    assert_eq!(buf.extend_with('#', 0), 0);
+    assert_eq!(buf.extend_with('#', 2), 2);
+    assert_eq!(buf, "[##");
+    assert_eq!(buf.extend_with('ロ', 1), 0);
+    assert_eq!(buf.extend_with(' ', 10), 2);
+    assert_eq!(buf, "[##  ");
+
+    let mut buf = MediumStr::new();
+    assert_eq!(buf.extend_with('ロ', 100), 96);
+    assert_eq!(buf.remaining(), 0);
+    assert!(buf.chars().all(|ch| ch == 'ロ'));
+
+    let mut buf = BigStr::new();
+    assert_eq!(buf.extend_with(' ', usize::MAX), BigStr::capacity());
+    assert_eq!(buf.trim(), "");
+}
+
+#[test]
+fn should_extend_from_within() {
+    let mut buf = MediumStr::from_str("rロi");
+    buf.extend_from_within(1..4).expect("Should fit");
+    assert_eq!(buf, "rロiロ");
+    buf.extend_from_within(..).expect("Should fit");
+    assert_eq!(buf, "rロiロrロiロ");
+    buf.extend_from_within(0..0).expect("Should fit");
+    assert_eq!(buf, "rロiロrロiロ");
+
+    let mut buf = SmolStr::from_str("rロ");
+    buf.extend_from_within(1..).expect_err("Should overflow");
+    assert_eq!(buf, "rロ");
+    buf.extend_from_within(..=0).expect("Should fit");
+    assert_eq!(buf, "rロr");
+
+    let mut buf = BigStr::from_str("ロ");
+    buf.extend_from_within(..).expect("Should fit");
+    assert_eq!(buf, "ロロ");
+}
+
+#[test]
+fn should_capitalize() {
+    let cases = [
+        ("", ""),
+        ("hELLO", "Hello"),
+        ("1st PLACE", "1st place"),
+        ("ロRI", "ロri"),
+        ("éCOLE", "école"),
+    ];
+
+    for (input, expected) in cases {
+        let mut buf = MediumStr::from_str(input);
+        assert_eq!(buf.into_capitalized(), expected);
+        buf.make_capitalize();
+        assert_eq!(buf, expected);
+
+        let mut buf = BigStr::from_str(input);
+        assert_eq!(buf.into_capitalized(), expected);
+        buf.make_capitalize();
+        assert_eq!(buf, expected);
+    }
+
+    const CAPITALIZED: SmolStr = SmolStr::from_str("hELLo").into_capitalized();
+    assert_eq!(CAPITALIZED, "Hello");
+}
+
+#[test]
+fn should_convert_to_snake_case() {
+    let cases = [
+        ("", ""),
+        ("HTTPServer", "http_server"),
+        ("myHTTPServer", "my_http_server"),
+        ("camelCase", "camel_case"),
+        ("PascalCase", "pascal_case"),
+        ("getX", "get_x"),
+        ("ABC", "abc"),
+        ("already_snake", "already_snake"),
+        ("utf8Value", "utf8_value"),
+        ("ロFooBar", "ロfoo_bar"),
+    ];
+
+    for (input, expected) in cases {
+        let mut buf = MediumStr::from_str(input);
+        buf.make_snake_case().expect("Should fit");
+        assert_eq!(buf, expected);
+
+        let mut buf = BigStr::from_str(input);
+        buf.make_snake_case().expect("Should fit");
+        assert_eq!(buf, expected);
+    }
+
+    let mut buf = SmolStr::from_str("aBcDe");
+    buf.make_snake_case().expect_err("Should overflow");
+    assert_eq!(buf, "aBcDe");
+    let mut buf = SmolStr::from_str("aBcD");
+    buf.make_snake_case().expect_err("Should overflow");
+    let mut buf = SmolStr::from_str("aBc");
+    buf.make_snake_case().expect("Should fit");
+    assert_eq!(buf, "a_bc");
+}
+
+#[test]
+fn should_convert_to_camel_case() {
+    let cases = [
+        ("", ""),
+        ("http_server", "httpServer"),
+        ("HTTPServer", "httpServer"),
+        ("my_HTTP_server", "myHttpServer"),
+        ("_leading__double_", "leadingDouble"),
+        ("camelCase", "camelCase"),
+        ("ロ_foo", "ロFoo"),
+    ];
+
+    for (input, expected) in cases {
+        let buf = MediumStr::from_str(input);
+        assert_eq!(buf.to_camel_case::<32>().expect("Should fit"), expected);
+        let buf = BigStr::from_str(input);
+        assert_eq!(buf.to_camel_case::<{str_buf::capacity(100_000)}>().expect("Should fit"), expected);
+    }
+
+    let buf = MediumStr::from_str("http_server");
+    assert_eq!(buf.to_camel_case::<11>().expect("Should fit"), "httpServer");
+    buf.to_camel_case::<10>().expect_err("Should overflow");
+}
+
+#[test]
+fn should_dedup_whitespace() {
+    let cases = [
+        ("", ""),
+        ("ロri", "ロri"),
+        (" \t\n\u{a0} ", " "),
+        ("  ロ \t r\u{a0}\u{3000}i  ", " ロ r i "),
+        ("a\u{a0}b", "a b"),
+    ];
+
+    for (input, expected) in cases {
+        let mut buf = MediumStr::from_str(input);
+        buf.dedup_whitespace();
+        assert_eq!(buf, expected);
+
+        let mut buf = BigStr::from_str(input);
+        buf.dedup_whitespace();
+        assert_eq!(buf, expected);
+    }
+
+    let mut buf = MediumStr::from_str("  ロ \t r\u{a0}\u{3000}i  ");
+    buf.dedup_whitespace();
+    buf.make_trim();
+    assert_eq!(buf, "ロ r i");
+}
+
+#[test]
+fn should_expand_tabs() {
+    let cases = [
+        ("", 4, ""),
+        ("\tr", 4, "    r"),
+        ("ab\tc", 4, "ab  c"),
+        ("abcd\te", 4, "abcd    e"),
+        ("abc\te", 4, "abc e"),
+        ("ロリ\tx\n\ty", 4, "ロリ  x\n    y"),
+        ("a\tb\tc", 0, "abc"),
+        ("a\tb", 1, "a b"),
+    ];
+
+    for (input, tab_width, expected) in cases {
+        let mut buf = MediumStr::from_str(input);
+        buf.expand_tabs(tab_width).expect("Should fit");
+        assert_eq!(buf, expected);
+
+        let mut buf = BigStr::from_str(input);
+        buf.expand_tabs(tab_width).expect("Should fit");
+        assert_eq!(buf, expected);
+    }
+
+    let mut buf = SmolStr::from_str("a\tb");
+    buf.expand_tabs(5).expect_err("Should overflow");
+    assert_eq!(buf, "a\tb");
+    buf.expand_tabs(4).expect("Fits exactly");
+    assert_eq!(buf, "a   b");
+
+    let mut buf = str_buf::StrBuf::<16>::from_str("\tX");
+    assert!(matches!(buf.expand_tabs(usize::MAX), Err(str_buf::StrBufError::Overflow)));
+    assert_eq!(buf, "\tX");
+    let mut buf = str_buf::StrBuf::<16>::from_str("X\t\tX");
+    assert!(matches!(buf.expand_tabs(usize::MAX), Err(str_buf::StrBufError::Overflow)));
+    assert_eq!(buf, "X\t\tX");
+}
+
+#[test]
+fn should_make_indent() {
+    let cases = [
+        ("", "  ", ""),
+        ("r", "  ", "  r"),
+        ("a\nb", "> ", "> a\n> b"),
+        ("a\nb\n", "> ", "> a\n> b\n"),
+        ("a\r\n\r\nb\r\n", "\t", "\ta\r\n\t\r\n\tb\r\n"),
+        ("\n", "ロ", "ロ\n"),
+        ("ロ\nリ", "・ ", "・ ロ\n・ リ"),
+        ("a\nb", "", "a\nb"),
+    ];
+
+    for (input, prefix, expected) in cases {
+        let mut buf = MediumStr::from_str(input);
+        buf.make_indent(prefix).expect("Should fit");
+        assert_eq!(buf, expected);
+
+        let mut buf = BigStr::from_str(input);
+        buf.make_indent(prefix).expect("Should fit");
+        assert_eq!(buf, expected);
+    }
+
+    let mut buf = SmolStr::from_str("a\nb");
+    buf.make_indent("ロ").expect_err("Should overflow");
+    assert_eq!(buf, "a\nb");
+    buf.make_indent(" ").expect("Fits exactly");
+    assert_eq!(buf, " a\n b");
+}
+
+#[test]
+fn should_push_join() {
+    let mut buf = MediumStr::new();
+    buf.push_join(core::iter::empty::<&str>(), ", ").expect("Should fit");
+    assert_eq!(buf, "");
+    buf.push_join(["single"], ", ").expect("Should fit");
+    assert_eq!(buf, "single");
+    buf.push_join(["ロ", "リ"], ", ").expect("Should fit");
+    assert_eq!(buf, "single, ロ, リ");
+    buf.push_join(core::iter::empty::<&str>(), ", ").expect("Should fit");
+    assert_eq!(buf, "single, ロ, リ");
+
+    let mut buf = SmolStr::from_str("a");
+    buf.push_join(["b", "c"], "ロ").expect_err("Should overflow on separator");
+    assert_eq!(buf, "a");
+    buf.push_join(["b", "cde"], ",").expect_err("Should overflow on last item");
+    assert_eq!(buf, "a");
+    buf.push_join(["b", "c"], ",").expect("Fits exactly");
+    assert_eq!(buf, "a,b,c");
+
+    let mut buf = BigStr::new();
+    buf.push_join([SmolStr::from_str("ロ"), SmolStr::from_str("リ")], "").expect("Should fit");
+    assert_eq!(buf, "ロリ");
+}
+
+#[test]
+fn should_assign_content() {
+    let mut buf = SmolStr::from_str("ロr");
+    buf.assign("ri").expect("Should fit");
+    assert_eq!(buf, "ri");
+    buf.assign("ロリ").expect_err("Should overflow");
+    assert_eq!(buf, "ri");
+    buf.assign("").expect("Should fit");
+    assert_eq!(buf, "");
+
+    let mut buf = MediumStr::from_str("old");
+    buf.copy_from(&SmolStr::from_str("ロri")).expect("Should fit");
+    assert_eq!(buf, "ロri");
+    let mut small = SmolStr::from_str("old");
+    small.copy_from(&BigStr::from_str("ロリ")).expect_err("Should overflow");
+    assert_eq!(small, "old");
+    small.copy_from(&BigStr::from_str("ロri")).expect("Should fit");
+    assert_eq!(small, "ロri");
+}
+
+#[test]
+fn should_push_str_returning_remainder() {
+    let mut buf = SmolStr::new();
+    assert_eq!(buf.push_str_remainder("ri"), "");
+    assert_eq!(buf.push_str_remainder("rロi"), "ロi");
+    assert_eq!(buf, "rir");
+    assert_eq!(buf.push_str_remainder("ロi"), "ロi");
+    buf.clear();
+
+    let mut text = "ロリロリ";
+    let mut chunks = Vec::new();
+    while !text.is_empty() {
+        text = buf.push_str_remainder(text);
+        chunks.push(buf.as_str().to_owned());
+        buf.clear();
+    }
+    assert_eq!(chunks, ["ロ", "リ", "ロ", "リ"]);
+}
+
+#[test]
+fn should_push_all_or_nothing() {
+    let mut buf = MediumStr::new();
+    buf.push_all(&[]).expect("Should fit");
+    buf.push_all(&["<", "ロリ", ">"]).expect("Should fit");
+    assert_eq!(buf, "<ロリ>");
+
+    let mut buf = SmolStr::from_str("$");
+    buf.push_all(&["ab", "c", "ロ"]).expect_err("Last part should overflow");
+    assert_eq!(buf, "$");
+    assert_eq!(buf.len(), 1);
+    buf.push_all(&["ab", "", "cd"]).expect("Fits exactly");
+    assert_eq!(buf, "$abcd");
+}
+
+#[test]
+fn should_push_line_atomically() {
+    let mut buf = MediumStr::new();
+    buf.push_line("ロ").expect("Should fit");
+    buf.push_line("").expect("Should fit");
+    buf.push_line_crlf("AT").expect("Should fit");
+    assert_eq!(buf, "ロ\n\nAT\r\n");
+
+    let mut buf = SmolStr::from_str("ab");
+    buf.push_line("cde").expect_err("Terminator should overflow");
+    assert_eq!(buf, "ab");
+    buf.push_line_crlf("cd").expect_err("Terminator should overflow");
+    assert_eq!(buf, "ab");
+    buf.push_line("c").expect("Fits exactly");
+    assert_eq!(buf, "abc\n");
+}
+
+#[test]
+fn should_rollback_to_checkpoint() {
+    let mut buf = MediumStr::from_str("ロ");
+    let checkpoint = buf.checkpoint();
+    buf.push_str("ri");
+    buf.rollback(checkpoint);
+    assert_eq!(buf, "ロ");
+    buf.rollback(buf.len() + 1);
+    assert_eq!(buf, "ロ");
+    buf.rollback(1);
+    assert_eq!(buf, "");
+}
+
+#[test]
+fn should_discard_uncommitted_transaction() {
+    use fmt::Write;
+
+    let mut buf = SmolStr::from_str("ロ");
+    {
+        let mut transaction = buf.transaction();
+        write!(transaction, "{}", 1).expect("Should fit");
+        assert_eq!(*transaction, "ロ1");
+        write!(transaction, "{}", 234).expect_err("Should overflow");
+    }
+    assert_eq!(buf, "ロ");
+
+    let mut transaction = buf.transaction();
+    write!(transaction, "{}", 12).expect("Should fit");
+    transaction.commit();
+    assert_eq!(buf, "ロ12");
+
+    let mut transaction = buf.transaction();
+    transaction.push_str("3");
+    transaction.rollback();
+    assert_eq!(buf, "ロ12");
+}
+
+#[test]
+fn should_discard_transaction_on_panic() {
+    let mut buf = MediumStr::from_str("ロ");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut transaction = buf.transaction();
+        transaction.push_str("ri");
+        panic!("boom");
+    }));
+    assert!(result.is_err());
+    assert_eq!(buf, "ロ");
+}
+
+#[test]
+fn should_swap_with_different_capacity() {
+    let mut small = SmolStr::from_str("ロ");
+    let mut medium = MediumStr::from_str("ri");
+    small.swap_with(&mut medium).expect("Should fit");
+    assert_eq!(small, "ri");
+    assert_eq!(medium, "ロ");
+    medium.swap_with(&mut small).expect("Should fit");
+    assert_eq!(small, "ロ");
+    assert_eq!(medium, "ri");
+
+    let mut big = BigStr::from_str("ロリ");
+    small.swap_with(&mut big).expect_err("Should overflow");
+    big.swap_with(&mut small).expect_err("Should overflow");
+    assert_eq!(small, "ロ");
+    assert_eq!(big, "ロリ");
+
+    let mut empty = SmolStr::new();
+    big.truncate_str(3);
+    big.swap_with(&mut empty).expect("Should fit");
+    assert_eq!(big, "");
+    assert_eq!(empty, "ロ");
+}
+
+#[test]
+fn should_commit_written_bytes() {
+    let mut text = SmolStr::from_str("a");
+    text.commit_from(|region| {
+        region[..3].copy_from_slice("ロ".as_bytes());
+        region.len() + 1
+    }).expect_err("Should overflow");
+    match text.commit_from(|region| {
+        region[..3].copy_from_slice("ロ".as_bytes());
+        2
+    }).expect_err("Should be incomplete char") {
+        str_buf::StrBufError::Utf8(error) => assert_eq!(error.valid_up_to(), 0),
+        error => panic!("Unexpected error: {}", error),
+    }
+    assert_eq!(text, "a");
+    text.commit_from(|region| {
+        region[..3].copy_from_slice("ロ".as_bytes());
+        3
+    }).expect("Should commit");
+    assert_eq!(text, "aロ");
+    text.commit_from(|_| 0).expect("Should commit nothing");
+    assert_eq!(text, "aロ");
+
+    let mut text = MediumStr::from_str("ab");
+    match text.commit_from(|region| {
+        region[0] = b'c';
+        region[1] = 0x80;
+        2
+    }).expect_err("Should be invalid") {
+        str_buf::StrBufError::Utf8(error) => assert_eq!(error.valid_up_to(), 1),
+        error => panic!("Unexpected error: {}", error),
+    }
+    assert_eq!(text, "ab");
+    text.commit_from(|region| {
+        region[0] = b'c';
+        1
+    }).expect("Should commit valid part");
+    assert_eq!(text, "abc");
+}
+
+#[test]
+fn should_commit_unwritten_bytes_as_zeroes() {
+    //Claiming bytes that were never written must not read uninitialized memory
+    let mut text = str_buf::StrBuf::<16>::new();
+    text.commit_from(|_| 2).expect("Should commit zeroed bytes");
+    assert_eq!(text, "\0\0");
+}
+
+#[test]
+fn should_accumulate_chunked_utf8() {
+    use str_buf::{Utf8Accumulator, PushBytes, Utf8PushError};
+
+    let bytes = "aロリ".as_bytes();
+    let mut acc = Utf8Accumulator::new();
+    let mut text = MediumStr::new();
+
+    let result = acc.push_bytes(&mut text, &bytes[..2]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 2, incomplete: 1, truncated: false });
+    assert_eq!(text, "a");
+    assert_eq!(acc.pending(), &bytes[1..2]);
+
+    let result = acc.push_bytes(&mut text, &bytes[2..3]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 1, incomplete: 2, truncated: false });
+    assert_eq!(text, "a");
+
+    let result = acc.push_bytes(&mut text, &bytes[3..]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 4, incomplete: 0, truncated: false });
+    assert_eq!(text, "aロリ");
+    assert!(acc.pending().is_empty());
+
+    let error = acc.push_bytes(&mut text, b"b\xFFc").expect_err("Should be invalid");
+    assert_eq!(error, Utf8PushError { consumed: 1, invalid_len: 1 });
+    assert_eq!(text, "aロリb");
+
+    acc.push_bytes(&mut text, &bytes[1..2]).expect("Should be incomplete");
+    let error = acc.push_bytes(&mut text, b"c").expect_err("Should be invalid");
+    assert_eq!(error, Utf8PushError { consumed: 0, invalid_len: 0 });
+    assert!(acc.pending().is_empty());
+    acc.push_bytes(&mut text, b"c").expect("Should resume");
+    assert_eq!(text, "aロリbc");
+}
+
+#[test]
+fn should_accumulate_utf8_with_truncation() {
+    use str_buf::{Utf8Accumulator, PushBytes};
+
+    let bytes = "aロリ".as_bytes();
+    let mut acc = Utf8Accumulator::new();
+    let mut text = SmolStr::from_str("b");
+
+    let result = acc.push_bytes(&mut text, &bytes[..5]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 5, incomplete: 1, truncated: false });
+    assert_eq!(text, "baロ");
+    let result = acc.push_bytes(&mut text, &bytes[5..]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 0, incomplete: 1, truncated: true });
+    assert_eq!(text, "baロ");
+
+    acc.reset();
+    text.clear();
+    let result = acc.push_bytes(&mut text, "abcdロ".as_bytes()).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 4, incomplete: 0, truncated: true });
+
+    text.clear();
+    acc.push_bytes(&mut text, "abcロ".as_bytes()).expect("Should be valid");
+    assert_eq!(text, "abc");
+    acc.push_bytes(&mut text, &bytes[1..2]).expect("Should be incomplete");
+    let result = acc.push_bytes(&mut text, &bytes[2..4]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 0, incomplete: 1, truncated: true });
+    assert_eq!(text, "abc");
+
+    text.clear();
+    let result = acc.push_bytes(&mut text, &bytes[2..4]).expect("Should be valid");
+    assert_eq!(result, PushBytes { consumed: 2, incomplete: 0, truncated: false });
+    assert_eq!(text, "ロ");
+}
+
+#[test]
+fn should_push_bytes_lossy() {
+    let mut text = MediumStr::new();
+    assert_eq!(text.push_bytes_lossy(b"a\xFFb\xE3\x83"), 8);
+    assert_eq!(text, "a\u{FFFD}b\u{FFFD}");
+    assert_eq!(text.push_bytes_lossy("ロ".as_bytes()), 3);
+    assert_eq!(text, "a\u{FFFD}b\u{FFFD}ロ");
+
+    let mut text = SmolStr::from_str("ab");
+    assert_eq!(text.push_bytes_lossy(b"c\xFF"), 1);
+    assert_eq!(text, "abc");
+
+    let mut text = SmolStr::new();
+    assert_eq!(text.push_bytes_lossy(b"\xFF\xFF"), 3);
+    assert_eq!(text, "\u{FFFD}");
+
+    let text = SmolStr::from_bytes_lossy(b"ab\xC0cd");
+    assert_eq!(text, "ab\u{FFFD}");
+    let text = BigStr::from_bytes_lossy(b"ab\xC0cd");
+    assert_eq!(text, "ab\u{FFFD}cd");
+}
+
+#[test]
+fn should_append_format() {
+    let mut text = SmolStr::from_str("a");
+    text.append_format(format_args!("{}{}", 1, 'b')).expect("Should fit");
+    assert_eq!(text, "a1b");
+    text.append_format(format_args!("{}{}", 2, "cd")).expect_err("Should overflow");
+    assert_eq!(text, "a1b");
+    text.append_format(format_args!("{}", "ロ")).expect_err("Should overflow");
+    assert_eq!(text, "a1b");
+
+    let mut text = MediumStr::new();
+    text.append_format(format_args!("{:>4}", 'ロ')).expect("Should fit");
+    assert_eq!(text, "   ロ");
+}
+
+#[test]
+fn should_extend_and_stop_on_overflow() {
+    let mut consumed = 0;
+    let mut text = SmolStr::from_str("a");
+    text.extend("bcロde".chars().inspect(|_| consumed += 1));
+    assert_eq!(text, "abc");
+    assert_eq!(consumed, 3);
+
+    let mut consumed = 0;
+    let mut text = SmolStr::new();
+    text.extend(["ab", "cdef", "g"].iter().copied().inspect(|_| consumed += 1));
+    assert_eq!(text, "abcde");
+    assert_eq!(consumed, 2);
+
+    let mut consumed = 0;
+    let mut text = SmolStr::new();
+    text.extend(["ab", "cde", "f"].iter().copied().inspect(|_| consumed += 1));
+    assert_eq!(text, "abcde");
+    assert_eq!(consumed, 2);
+    text.extend("f".chars().inspect(|_| consumed += 1));
+    assert_eq!(consumed, 2);
+
+    let mut text = MediumStr::from_str("ロ");
+    text.extend([SmolStr::from_str("リ"), SmolStr::from_str("!")].iter().copied());
+    text.extend(core::iter::empty::<char>());
+    assert_eq!(text, "ロリ!");
+}
+
+#[test]
+fn should_add_with_operators() {
+    let mut text = SmolStr::from_str("a") + "b" + 'ロ';
+    assert_eq!(text, "abロ");
+    text.clear();
+    text += "ロ";
+    text += 'a';
+    text += "b";
+    assert_eq!(text, "ロab");
+
+    let mut text = MediumStr::new() + "";
+    text += "ロリ";
+    assert_eq!(text, "ロリ");
+}
+
+#[test]
+fn should_write_through_dyn_str_buf_like() {
+    use core::fmt::Write;
+    use str_buf::StrBufLike;
+
+    fn report(out: &mut dyn StrBufLike, code: u32) -> bool {
+        out.clear();
+        write!(out, "error={}", code).is_ok()
+    }
+
+    let mut small = SmolStr::new();
+    let mut big = BigStr::new();
+    assert!(!report(&mut small, 42));
+    assert_eq!(small, "error");
+    assert!(report(&mut big, 42));
+    assert_eq!(big, "error=42");
+
+    let buffers: [&mut dyn StrBufLike; 2] = [&mut small, &mut big];
+    for buf in buffers {
+        buf.clear();
+        assert_eq!(buf.push_str("ロリ"), buf.capacity().min(6) / 3 * 3);
+        buf.truncate(4);
+        assert_eq!(buf.as_str(), "ロ");
+        assert_eq!(buf.remaining(), buf.capacity() - 3);
+    }
+}
+
+#[test]
+fn should_mirror_string_api() {
+    let mut text = SmolStr::from_str("a");
+    assert_eq!(text.capacity_of(), SmolStr::capacity());
+    text.reserve(4);
+    text.try_reserve(4).expect("Should fit");
+    text.try_reserve(5).expect_err("Should not fit");
+    text.push_char('ロ');
+    assert_eq!(text, "aロ");
+    text.reserve(1);
+    text.push_char('b');
+    assert_eq!(text, "aロb");
+    text.reserve(0);
+    text.try_reserve(1).expect_err("Buffer is full");
+
+    assert_eq!(BigStr::new().capacity_of(), 67_000 - core::mem::size_of::<usize>());
+    assert_eq!(StrBuf::<0>::new().capacity_of(), 0);
+}
@@ -0,0 +1,37 @@
+use str_buf::StrBuf;
+
+type SmolStr = StrBuf<6>;
+type BigStr = StrBuf<67_000>;
+
+#[test]
+fn should_search_same_as_const() {
+    let mut text = BigStr::new();
+    text.extend_with('a', 64 * 1024 - 16);
+    text.push_str("ロ:needle:");
+
+    assert_eq!(text.memchr(b':'), text.find_byte(b':'));
+    assert_eq!(text.memrchr(b':'), text.rfind_byte(b':'));
+    assert_eq!(text.memchr(b'z'), None);
+    assert_eq!(text.memrchr(b'z'), None);
+    assert_eq!(text.memmem("needle"), text.const_find("needle"));
+    assert_eq!(text.memmem("ロ:"), Some(64 * 1024 - 16));
+    assert_eq!(text.memmem("needles"), None);
+    assert_eq!(text.memmem(""), Some(0));
+
+    let empty = SmolStr::new();
+    assert_eq!(empty.memchr(b'a'), None);
+    assert_eq!(empty.memrchr(b'a'), None);
+    assert_eq!(empty.memmem("a"), None);
+    assert_eq!(empty.memmem(""), Some(0));
+}
+
+#[test]
+fn should_replace_with_search() {
+    let mut text = BigStr::new();
+    text.push_repeat("ab", 1000);
+    assert_eq!(text.make_replace("b", "ロ").expect("Should fit"), 1000);
+    assert_eq!(text.memmem("b"), None);
+    assert_eq!(text.replace_char('ロ', 'c').expect("Should fit"), 1000);
+    assert_eq!(text.len(), 2000);
+    assert_eq!(text.memrchr(b'c'), Some(1999));
+}
@@ -0,0 +1,68 @@
+use str_buf::StrBuf;
+
+#[allow(unused)]
+type MediumStr = StrBuf<290>;
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn should_measure_display_width() {
+    let text = MediumStr::from_str("aロリ");
+    assert_eq!(text.display_width(), 5);
+    let text = MediumStr::from_str("e\u{301}ロ");
+    assert_eq!(text.display_width(), 3);
+    assert_eq!(MediumStr::new().display_width(), 0);
+}
+
+#[cfg(feature = "unicode-width")]
+#[test]
+fn should_truncate_to_display_width() {
+    let mut text = MediumStr::from_str("aロリ");
+    text.truncate_to_width(4);
+    assert_eq!(text, "aロ");
+    assert_eq!(text.display_width(), 3);
+    text.truncate_to_width(3);
+    assert_eq!(text, "aロ");
+    text.truncate_to_width(2);
+    assert_eq!(text, "a");
+    text.truncate_to_width(0);
+    assert_eq!(text, "");
+
+    let mut text = MediumStr::from_str("e\u{301}ロe\u{301}");
+    text.truncate_to_width(1);
+    assert_eq!(text, "e\u{301}");
+    let mut text = MediumStr::from_str("e\u{301}ロe\u{301}");
+    text.truncate_to_width(4);
+    assert_eq!(text, "e\u{301}ロe\u{301}");
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn should_pop_graphemes() {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let mut text = MediumStr::from_str("ae\u{301}");
+    text.push_str(family);
+
+    assert_eq!(text.pop_grapheme(), Some(family));
+    assert_eq!(text, "ae\u{301}");
+    assert_eq!(text.pop_grapheme(), Some("e\u{301}"));
+    assert_eq!(text.pop_grapheme(), Some("a"));
+    assert_eq!(text.pop_grapheme(), None);
+    assert_eq!(text, "");
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn should_truncate_graphemes() {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let mut text = MediumStr::from_str(family);
+    text.push_str("e\u{301}ロ");
+
+    text.truncate_graphemes(3);
+    assert_eq!(text.len(), family.len() + 6);
+    text.truncate_graphemes(2);
+    assert!(text.ends_with("e\u{301}"));
+    text.truncate_graphemes(1);
+    assert_eq!(text, family);
+    text.truncate_graphemes(0);
+    assert_eq!(text, "");
+}